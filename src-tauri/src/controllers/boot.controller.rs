@@ -0,0 +1,23 @@
+/* services */
+use crate::services::boot_service::BootService;
+
+/* models */
+use crate::models::{AppError, ResponseModel};
+
+#[allow(non_snake_case)]
+pub struct BootController {
+  bootService: BootService,
+}
+
+#[allow(non_snake_case)]
+impl BootController {
+  pub fn new() -> Self {
+    Self {
+      bootService: BootService,
+    }
+  }
+
+  pub fn getBootAnalysis(&self) -> Result<ResponseModel, AppError> {
+    self.bootService.getBootAnalysis()
+  }
+}
@@ -2,7 +2,9 @@
 use crate::services::cleaner_service::CleanerService;
 
 /* models */
-use crate::models::ResponseModel;
+use crate::models::{
+  AppError, JournalVacuumModel, LogRetentionModel, ResponseModel, SettingsModel,
+};
 
 #[allow(non_snake_case)]
 pub struct CleanerController {
@@ -11,70 +13,81 @@ pub struct CleanerController {
 
 #[allow(non_snake_case)]
 impl CleanerController {
-  pub fn new() -> Self {
+  pub fn new(settings: SettingsModel) -> Self {
     Self {
-      cleanerService: CleanerService,
+      cleanerService: CleanerService::new(settings),
     }
   }
 
-  pub fn getCacheFiles(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn getCacheFiles(&self) -> Result<ResponseModel, AppError> {
     self.cleanerService.getCacheFiles()
   }
 
-  pub fn getTrashFiles(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn getTrashFiles(&self) -> Result<ResponseModel, AppError> {
     self.cleanerService.getTrashFiles()
   }
 
-  pub fn getSystemLogs(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn getSystemLogs(&self) -> Result<ResponseModel, AppError> {
     self.cleanerService.getSystemLogs()
   }
 
-  pub fn getLargeFiles(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn analyzeSystemLogs(&self) -> Result<ResponseModel, AppError> {
+    self.cleanerService.analyzeSystemLogs()
+  }
+
+  pub fn getLogFamilies(&self) -> Result<ResponseModel, AppError> {
+    self.cleanerService.getLogFamilies()
+  }
+
+  pub fn applyLogRetention(&self, retention: LogRetentionModel) -> Result<ResponseModel, AppError> {
+    self.cleanerService.applyLogRetention(retention)
+  }
+
+  pub fn getLargeFiles(&self) -> Result<ResponseModel, AppError> {
     self.cleanerService.getLargeFiles()
   }
 
-  pub fn previewFile(&self, path: String) -> Result<ResponseModel, ResponseModel> {
+  pub fn previewFile(&self, path: String) -> Result<ResponseModel, AppError> {
     self.cleanerService.previewFile(path)
   }
 
-  pub fn clearSelectedCacheFiles(
-    &self,
-    paths: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
+  pub fn clearSelectedCacheFiles(&self, paths: Vec<String>) -> Result<ResponseModel, AppError> {
     self.cleanerService.clearSelectedCacheFiles(paths)
   }
 
-  pub fn clearSelectedTrashFiles(
-    &self,
-    paths: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
+  pub fn clearSelectedTrashFiles(&self, paths: Vec<String>) -> Result<ResponseModel, AppError> {
     self.cleanerService.clearSelectedTrashFiles(paths)
   }
 
-  pub fn clearSelectedLogFiles(&self, paths: Vec<String>) -> Result<ResponseModel, ResponseModel> {
+  pub fn clearSelectedLogFiles(&self, paths: Vec<String>) -> Result<ResponseModel, AppError> {
     self.cleanerService.clearSelectedLogFiles(paths)
   }
 
-  pub fn clearSelectedLargeFiles(
-    &self,
-    paths: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
+  pub fn clearSelectedLargeFiles(&self, paths: Vec<String>) -> Result<ResponseModel, AppError> {
     self.cleanerService.clearSelectedLargeFiles(paths)
   }
 
-  pub fn clearTrash(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn clearTrash(&self) -> Result<ResponseModel, AppError> {
     self.cleanerService.clearTrash()
   }
 
-  pub fn clearCache(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn clearCache(&self) -> Result<ResponseModel, AppError> {
     self.cleanerService.clearCache()
   }
 
-  pub fn clearAllLogs(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn clearAllLogs(&self) -> Result<ResponseModel, AppError> {
     self.cleanerService.clearAllLogs()
   }
 
-  pub fn clearAllLargeFiles(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn getJournalUsage(&self) -> Result<ResponseModel, AppError> {
+    self.cleanerService.getJournalUsage()
+  }
+
+  pub fn vacuumJournal(&self, vacuum: JournalVacuumModel) -> Result<ResponseModel, AppError> {
+    self.cleanerService.vacuumJournal(vacuum)
+  }
+
+  pub fn clearAllLargeFiles(&self) -> Result<ResponseModel, AppError> {
     self.cleanerService.clearAllLargeFiles()
   }
 }
@@ -2,7 +2,7 @@
 use crate::services::dashboard_service::DashboardService;
 
 /* models */
-use crate::models::ResponseModel;
+use crate::models::{AppError, ResponseModel, ServiceScope, SettingsModel};
 
 #[allow(non_snake_case)]
 pub struct DashboardController {
@@ -11,29 +11,37 @@ pub struct DashboardController {
 
 #[allow(non_snake_case)]
 impl DashboardController {
-  pub fn new() -> Self {
+  pub fn new(settings: SettingsModel) -> Self {
     Self {
-      dashboardService: DashboardService,
+      dashboardService: DashboardService::new(settings),
     }
   }
 
-  pub fn getRunningServices(&self) -> Result<ResponseModel, ResponseModel> {
-    self.dashboardService.getRunningServices()
+  pub fn getRunningServices(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    self.dashboardService.getRunningServices(scope)
   }
 
-  pub fn getCacheSummary(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn getFailedUnits(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    self.dashboardService.getFailedUnits(scope)
+  }
+
+  pub fn getServiceSummary(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    self.dashboardService.getServiceSummary(scope)
+  }
+
+  pub fn getCacheSummary(&self) -> Result<ResponseModel, AppError> {
     self.dashboardService.getCacheSummary()
   }
 
-  pub fn getTrashSummary(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn getTrashSummary(&self) -> Result<ResponseModel, AppError> {
     self.dashboardService.getTrashSummary()
   }
 
-  pub fn getLogSummary(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn getLogSummary(&self) -> Result<ResponseModel, AppError> {
     self.dashboardService.getLogSummary()
   }
 
-  pub fn getLargeFilesSummary(&self) -> Result<ResponseModel, ResponseModel> {
+  pub fn getLargeFilesSummary(&self) -> Result<ResponseModel, AppError> {
     self.dashboardService.getLargeFilesSummary()
   }
 }
@@ -0,0 +1,46 @@
+/* services */
+use crate::services::elevation_service::{ElevationService, PrivilegedQueueState};
+
+/* models */
+use crate::models::{AppError, PrivilegedOperationModel, ResponseModel};
+
+#[allow(non_snake_case)]
+pub struct ElevationController {
+  elevationService: ElevationService,
+}
+
+#[allow(non_snake_case)]
+impl ElevationController {
+  pub fn new(queue: PrivilegedQueueState) -> Self {
+    Self {
+      elevationService: ElevationService::new(queue),
+    }
+  }
+
+  pub fn getElevationStatus(&self) -> Result<ResponseModel, AppError> {
+    self.elevationService.getElevationStatus()
+  }
+
+  pub fn elevate(&self) -> Result<ResponseModel, AppError> {
+    self.elevationService.elevate()
+  }
+
+  pub fn dropPrivileges(&self) -> Result<ResponseModel, AppError> {
+    self.elevationService.dropPrivileges()
+  }
+
+  pub fn queuePrivilegedOperations(
+    &self,
+    operations: Vec<PrivilegedOperationModel>,
+  ) -> Result<ResponseModel, AppError> {
+    self.elevationService.queuePrivilegedOperations(operations)
+  }
+
+  pub fn clearPrivilegedQueue(&self) -> Result<ResponseModel, AppError> {
+    self.elevationService.clearPrivilegedQueue()
+  }
+
+  pub fn runPrivilegedQueue(&self) -> Result<ResponseModel, AppError> {
+    self.elevationService.runPrivilegedQueue()
+  }
+}
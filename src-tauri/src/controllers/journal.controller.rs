@@ -0,0 +1,38 @@
+/* services */
+use crate::services::journal_service::{JournalFollowState, JournalService};
+
+/* models */
+use crate::models::{AppError, JournalEntryModel, JournalQueryModel, ResponseModel};
+
+#[allow(non_snake_case)]
+pub struct JournalController {
+  journalService: JournalService,
+}
+
+#[allow(non_snake_case)]
+impl JournalController {
+  pub fn new(follows: JournalFollowState) -> Self {
+    Self {
+      journalService: JournalService::new(follows),
+    }
+  }
+
+  pub fn getServiceJournal(&self, query: JournalQueryModel) -> Result<ResponseModel, AppError> {
+    self.journalService.getServiceJournal(query)
+  }
+
+  pub fn followServiceJournal<F>(
+    &self,
+    query: JournalQueryModel,
+    onEntry: F,
+  ) -> Result<ResponseModel, AppError>
+  where
+    F: Fn(u32, JournalEntryModel) + Send + 'static,
+  {
+    self.journalService.followServiceJournal(query, onEntry)
+  }
+
+  pub fn stopFollowingJournal(&self, followId: u32) -> Result<ResponseModel, AppError> {
+    self.journalService.stopFollowingJournal(followId)
+  }
+}
@@ -0,0 +1,27 @@
+/* services */
+use crate::services::kernel_service::KernelService;
+
+/* models */
+use crate::models::{AppError, ResponseModel};
+
+#[allow(non_snake_case)]
+pub struct KernelController {
+  kernelService: KernelService,
+}
+
+#[allow(non_snake_case)]
+impl KernelController {
+  pub fn new() -> Self {
+    Self {
+      kernelService: KernelService,
+    }
+  }
+
+  pub fn getKernels(&self) -> Result<ResponseModel, AppError> {
+    self.kernelService.getKernels()
+  }
+
+  pub fn removeKernels(&self, versions: Vec<String>) -> Result<ResponseModel, AppError> {
+    self.kernelService.removeKernels(versions)
+  }
+}
@@ -0,0 +1,27 @@
+/* services */
+use crate::services::knowledge_service::KnowledgeService;
+
+/* models */
+use crate::models::{AppError, ResponseModel, ServiceScope};
+
+#[allow(non_snake_case)]
+pub struct KnowledgeController {
+  knowledgeService: KnowledgeService,
+}
+
+#[allow(non_snake_case)]
+impl KnowledgeController {
+  pub fn new() -> Self {
+    Self {
+      knowledgeService: KnowledgeService,
+    }
+  }
+
+  pub fn getKnowledgeBase(&self) -> Result<ResponseModel, AppError> {
+    self.knowledgeService.getKnowledgeBase()
+  }
+
+  pub fn getServiceRecommendations(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    self.knowledgeService.getServiceRecommendations(scope)
+  }
+}
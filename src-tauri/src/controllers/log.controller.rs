@@ -0,0 +1,23 @@
+/* services */
+use crate::services::log_service::LogService;
+
+/* models */
+use crate::models::{AppError, LogPageQueryModel, ResponseModel};
+
+#[allow(non_snake_case)]
+pub struct LogController {
+  logService: LogService,
+}
+
+#[allow(non_snake_case)]
+impl LogController {
+  pub fn new() -> Self {
+    Self {
+      logService: LogService,
+    }
+  }
+
+  pub fn getLogPage(&self, query: LogPageQueryModel) -> Result<ResponseModel, AppError> {
+    self.logService.getLogPage(query)
+  }
+}
@@ -6,3 +6,39 @@ pub mod dashboard_controller;
 
 #[path = "system.controller.rs"]
 pub mod system_controller;
+
+#[path = "schedule.controller.rs"]
+pub mod schedule_controller;
+
+#[path = "monitor.controller.rs"]
+pub mod monitor_controller;
+
+#[path = "settings.controller.rs"]
+pub mod settings_controller;
+
+#[path = "profile.controller.rs"]
+pub mod profile_controller;
+
+#[path = "journal.controller.rs"]
+pub mod journal_controller;
+
+#[path = "boot.controller.rs"]
+pub mod boot_controller;
+
+#[path = "knowledge.controller.rs"]
+pub mod knowledge_controller;
+
+#[path = "snapshot.controller.rs"]
+pub mod snapshot_controller;
+
+#[path = "elevation.controller.rs"]
+pub mod elevation_controller;
+
+#[path = "log.controller.rs"]
+pub mod log_controller;
+
+#[path = "package.controller.rs"]
+pub mod package_controller;
+
+#[path = "kernel.controller.rs"]
+pub mod kernel_controller;
@@ -0,0 +1,24 @@
+/* services */
+use crate::services::monitor_service::MonitorService;
+use crate::services::settings_service::SettingsState;
+
+/* models */
+use crate::models::{AppError, ResponseModel};
+
+#[allow(non_snake_case)]
+pub struct MonitorController {
+  monitorService: MonitorService,
+}
+
+#[allow(non_snake_case)]
+impl MonitorController {
+  pub fn new(settings: SettingsState) -> Self {
+    Self {
+      monitorService: MonitorService::new(settings),
+    }
+  }
+
+  pub fn getDiskUsage(&self) -> Result<ResponseModel, AppError> {
+    self.monitorService.getDiskUsage()
+  }
+}
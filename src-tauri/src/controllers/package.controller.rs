@@ -0,0 +1,38 @@
+/* services */
+use crate::services::package_service::PackageService;
+
+/* models */
+use crate::models::{AppError, PackageCacheCleanModel, ResponseModel};
+
+#[allow(non_snake_case)]
+pub struct PackageController {
+  packageService: PackageService,
+}
+
+#[allow(non_snake_case)]
+impl PackageController {
+  pub fn new() -> Self {
+    Self {
+      packageService: PackageService,
+    }
+  }
+
+  pub fn getPackageCache(&self) -> Result<ResponseModel, AppError> {
+    self.packageService.getPackageCache()
+  }
+
+  pub fn cleanPackageCache(
+    &self,
+    clean: PackageCacheCleanModel,
+  ) -> Result<ResponseModel, AppError> {
+    self.packageService.cleanPackageCache(clean)
+  }
+
+  pub fn getOrphanPackages(&self) -> Result<ResponseModel, AppError> {
+    self.packageService.getOrphanPackages()
+  }
+
+  pub fn removeOrphanPackages(&self, packages: Vec<String>) -> Result<ResponseModel, AppError> {
+    self.packageService.removeOrphanPackages(packages)
+  }
+}
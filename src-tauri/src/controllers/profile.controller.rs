@@ -0,0 +1,36 @@
+/* services */
+use crate::services::profile_service::ProfileService;
+use crate::services::settings_service::SettingsState;
+
+/* models */
+use crate::models::{AppError, CleanupProfileModel, ResponseModel};
+
+#[allow(non_snake_case)]
+pub struct ProfileController {
+  profileService: ProfileService,
+}
+
+#[allow(non_snake_case)]
+impl ProfileController {
+  pub fn new(settings: SettingsState) -> Self {
+    Self {
+      profileService: ProfileService::new(settings),
+    }
+  }
+
+  pub fn getProfiles(&self) -> Result<ResponseModel, AppError> {
+    self.profileService.getProfiles()
+  }
+
+  pub fn saveProfile(&self, profile: CleanupProfileModel) -> Result<ResponseModel, AppError> {
+    self.profileService.saveProfile(profile)
+  }
+
+  pub fn removeProfile(&self, id: &str) -> Result<ResponseModel, AppError> {
+    self.profileService.removeProfile(id)
+  }
+
+  pub fn runProfile(&self, id: &str, planToken: Option<&str>) -> Result<ResponseModel, AppError> {
+    self.profileService.runProfile(id, planToken)
+  }
+}
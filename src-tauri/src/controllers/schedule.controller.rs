@@ -0,0 +1,36 @@
+/* services */
+use crate::services::schedule_service::ScheduleService;
+use crate::services::settings_service::SettingsState;
+
+/* models */
+use crate::models::{AppError, ResponseModel, ScheduleModel};
+
+#[allow(non_snake_case)]
+pub struct ScheduleController {
+  scheduleService: ScheduleService,
+}
+
+#[allow(non_snake_case)]
+impl ScheduleController {
+  pub fn new(settings: SettingsState) -> Self {
+    Self {
+      scheduleService: ScheduleService::new(settings),
+    }
+  }
+
+  pub fn getSchedules(&self) -> Result<ResponseModel, AppError> {
+    self.scheduleService.getSchedules()
+  }
+
+  pub fn saveSchedule(&self, schedule: ScheduleModel) -> Result<ResponseModel, AppError> {
+    self.scheduleService.saveSchedule(schedule)
+  }
+
+  pub fn removeSchedule(&self, id: &str) -> Result<ResponseModel, AppError> {
+    self.scheduleService.removeSchedule(id)
+  }
+
+  pub fn runSchedule(&self, id: &str) -> Result<ResponseModel, AppError> {
+    self.scheduleService.runSchedule(id)
+  }
+}
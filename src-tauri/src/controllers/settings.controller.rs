@@ -0,0 +1,31 @@
+/* services */
+use crate::services::settings_service::{SettingsService, SettingsState};
+
+/* models */
+use crate::models::{AppError, ResponseModel, SettingsModel};
+
+#[allow(non_snake_case)]
+pub struct SettingsController {
+  settingsService: SettingsService,
+}
+
+#[allow(non_snake_case)]
+impl SettingsController {
+  pub fn new() -> Self {
+    Self {
+      settingsService: SettingsService,
+    }
+  }
+
+  pub fn getSettings(&self, state: &SettingsState) -> Result<ResponseModel, AppError> {
+    self.settingsService.getSettings(state)
+  }
+
+  pub fn updateSettings(
+    &self,
+    state: &SettingsState,
+    settings: SettingsModel,
+  ) -> Result<ResponseModel, AppError> {
+    self.settingsService.updateSettings(state, settings)
+  }
+}
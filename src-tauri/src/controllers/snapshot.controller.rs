@@ -0,0 +1,43 @@
+/* services */
+use crate::services::snapshot_service::SnapshotService;
+
+/* models */
+use crate::models::{AppError, ResponseModel, ServiceScope};
+
+#[allow(non_snake_case)]
+pub struct SnapshotController {
+  snapshotService: SnapshotService,
+}
+
+#[allow(non_snake_case)]
+impl SnapshotController {
+  pub fn new() -> Self {
+    Self {
+      snapshotService: SnapshotService,
+    }
+  }
+
+  pub fn getServiceSnapshots(&self) -> Result<ResponseModel, AppError> {
+    self.snapshotService.getServiceSnapshots()
+  }
+
+  pub fn createServiceSnapshot(
+    &self,
+    name: &str,
+    scope: ServiceScope,
+  ) -> Result<ResponseModel, AppError> {
+    self.snapshotService.createServiceSnapshot(name, scope)
+  }
+
+  pub fn removeServiceSnapshot(&self, id: &str) -> Result<ResponseModel, AppError> {
+    self.snapshotService.removeServiceSnapshot(id)
+  }
+
+  pub fn diffServiceSnapshot(&self, id: &str) -> Result<ResponseModel, AppError> {
+    self.snapshotService.diffServiceSnapshot(id)
+  }
+
+  pub fn restoreServiceSnapshot(&self, id: &str) -> Result<ResponseModel, AppError> {
+    self.snapshotService.restoreServiceSnapshot(id)
+  }
+}
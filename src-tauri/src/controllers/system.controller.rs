@@ -2,7 +2,7 @@
 use crate::services::system_service::SystemService;
 
 /* models */
-use crate::models::ResponseModel;
+use crate::models::{AppError, ResponseModel, ServiceScope};
 
 #[allow(non_snake_case)]
 pub struct SystemController {
@@ -17,41 +17,161 @@ impl SystemController {
     }
   }
 
-  pub fn stopService(&self, service: &str) -> Result<ResponseModel, ResponseModel> {
-    self.systemService.stopService(service)
+  pub fn stopService(&self, scope: ServiceScope, service: &str) -> Result<ResponseModel, AppError> {
+    self.systemService.stopService(scope, service)
   }
 
   pub fn stopSelectedServices(
     &self,
+    scope: ServiceScope,
     services: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
-    self.systemService.stopSelectedServices(services)
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.stopSelectedServices(scope, services)
   }
 
-  pub fn openFile(
-    &self,
-    path: &str,
-    command: Option<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
+  pub fn openFile(&self, path: &str, command: Option<String>) -> Result<ResponseModel, AppError> {
     self.systemService.openFile(path, command)
   }
 
-  pub fn getAllServices(&self) -> Result<ResponseModel, ResponseModel> {
-    self.systemService.getAllServices()
+  pub fn getAllServices(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    self.systemService.getAllServices(scope)
+  }
+
+  pub fn getTimers(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    self.systemService.getTimers(scope)
   }
 
-  pub fn enableService(&self, service: &str) -> Result<ResponseModel, ResponseModel> {
-    self.systemService.enableService(service)
+  pub fn getServiceDetails(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.getServiceDetails(scope, service)
+  }
+
+  pub fn enableService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.enableService(scope, service)
   }
 
-  pub fn startService(&self, service: &str) -> Result<ResponseModel, ResponseModel> {
-    self.systemService.startService(service)
+  pub fn startService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.startService(scope, service)
   }
 
   pub fn enableSelectedServices(
     &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.enableSelectedServices(scope, services)
+  }
+
+  pub fn restartService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.restartService(scope, service)
+  }
+
+  pub fn reloadService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.reloadService(scope, service)
+  }
+
+  pub fn disableService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.disableService(scope, service)
+  }
+
+  pub fn maskService(&self, scope: ServiceScope, service: &str) -> Result<ResponseModel, AppError> {
+    self.systemService.maskService(scope, service)
+  }
+
+  pub fn unmaskService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.unmaskService(scope, service)
+  }
+
+  pub fn resetFailedService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.resetFailedService(scope, service)
+  }
+
+  pub fn resetFailedSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self
+      .systemService
+      .resetFailedSelectedServices(scope, services)
+  }
+
+  pub fn startSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.startSelectedServices(scope, services)
+  }
+
+  pub fn restartSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.restartSelectedServices(scope, services)
+  }
+
+  pub fn reloadSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.reloadSelectedServices(scope, services)
+  }
+
+  pub fn disableSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.disableSelectedServices(scope, services)
+  }
+
+  pub fn maskSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.maskSelectedServices(scope, services)
+  }
+
+  pub fn unmaskSelectedServices(
+    &self,
+    scope: ServiceScope,
     services: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
-    self.systemService.enableSelectedServices(services)
+  ) -> Result<ResponseModel, AppError> {
+    self.systemService.unmaskSelectedServices(scope, services)
   }
 }
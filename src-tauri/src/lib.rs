@@ -9,28 +9,118 @@ use tauri::command;
 
 /* routes */
 use routes::{
+  boot_route::getBootAnalysis,
   cleaner_route::{
-    clearAllLargeFiles, clearAllLogs, clearCache, clearSelectedCacheFiles, clearSelectedLargeFiles,
-    clearSelectedLogFiles, clearSelectedTrashFiles, clearTrash, getCacheFiles, getLargeFiles,
-    getSystemLogs, getTrashFiles, previewFile,
+    analyzeSystemLogs, applyLogRetention, clearAllLargeFiles, clearAllLogs, clearCache,
+    clearSelectedCacheFiles, clearSelectedLargeFiles, clearSelectedLogFiles,
+    clearSelectedTrashFiles, clearTrash, getCacheFiles, getJournalUsage, getLargeFiles,
+    getLogFamilies, getSystemLogs, getTrashFiles, previewFile, vacuumJournal,
   },
   dashboard_route::{
-    getCacheSummary, getLargeFilesSummary, getLogSummary, getSystemServices, getTrashSummary,
+    getCacheSummary, getFailedUnits, getLargeFilesSummary, getLogSummary, getServiceSummary,
+    getSystemServices, getTrashSummary,
+  },
+  elevation_route::{
+    clearPrivilegedQueue, dropPrivileges, elevate, getElevationStatus, queuePrivilegedOperations,
+    runPrivilegedQueue,
+  },
+  journal_route::{followServiceJournal, getServiceJournal, stopFollowingJournal},
+  kernel_route::{getKernels, removeKernels},
+  knowledge_route::{getKnowledgeBase, getServiceRecommendations},
+  log_route::getLogPage,
+  monitor_route::getDiskUsage,
+  package_route::{cleanPackageCache, getOrphanPackages, getPackageCache, removeOrphanPackages},
+  profile_route::{getProfiles, removeProfile, runProfile, saveProfile},
+  schedule_route::{getSchedules, removeSchedule, runScheduleNow, saveSchedule},
+  settings_route::{getSettings, updateSettings},
+  snapshot_route::{
+    createServiceSnapshot, diffServiceSnapshot, getServiceSnapshots, removeServiceSnapshot,
+    restoreServiceSnapshot,
   },
   system_route::{
-    enableSelectedServices, enableService, getAllServices, openFile, startService,
-    stopSelectedServices, stopService,
+    disableSelectedServices, disableService, enableSelectedServices, enableService, getAllServices,
+    getServiceDetails, getTimers, maskSelectedServices, maskService, openFile,
+    reloadSelectedServices, reloadService, resetFailedSelectedServices, resetFailedService,
+    restartSelectedServices, restartService, startSelectedServices, startService,
+    stopSelectedServices, stopService, unmaskSelectedServices, unmaskService,
   },
 };
 
+/* services */
+use services::elevation_service::PrivilegedQueueState;
+use services::journal_service::JournalFollowState;
+use services::monitor_service::MonitorService;
+use services::privileged_service::run_helper;
+use services::schedule_service::ScheduleService;
+use services::settings_service::{SettingsService, SettingsState};
+
+/* models */
+use models::SettingsModel;
+
 #[command]
 fn greet(name: &str) -> String {
   format!("Hello, {}!", name)
 }
 
+#[allow(non_snake_case)]
+pub fn runHeadless(args: &[String]) -> Option<i32> {
+  let mode = args.get(1).map(String::as_str);
+  // Started by the system bus as root; it needs none of the user's settings
+  if mode == Some("--privileged-helper") {
+    return match run_helper() {
+      Ok(()) => Some(0),
+      Err(error) => {
+        eprintln!("{}: {}", error.code, error.message);
+        Some(error.code.exitCode())
+      }
+    };
+  }
+  if !matches!(mode, Some("--run-schedule") | Some("--monitor")) {
+    return None;
+  }
+
+  let settings = match SettingsService.loadSettings() {
+    Ok(settings) => settings,
+    Err(error) => {
+      eprintln!("{}: {}", error.code, error.message);
+      return Some(error.code.exitCode());
+    }
+  };
+
+  match mode {
+    Some("--run-schedule") => {
+      let Some(id) = args.get(2) else {
+        eprintln!("Usage: cleanux --run-schedule <id>");
+        return Some(2);
+      };
+      match ScheduleService::new(SettingsState::new(settings)).runSchedule(id) {
+        Ok(response) => {
+          println!("{}", response.message);
+          Some(0)
+        }
+        Err(error) => {
+          eprintln!("{}: {}", error.code, error.message);
+          Some(error.code.exitCode())
+        }
+      }
+    }
+    Some("--monitor") => {
+      MonitorService::new(SettingsState::new(settings)).runMonitor();
+      Some(0)
+    }
+    _ => None,
+  }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 #[allow(non_snake_case)]
 pub fn run() {
+  let settings = SettingsService.loadSettings().unwrap_or_else(|error| {
+    eprintln!("{}: {}", error.code, error.message);
+    SettingsModel::default()
+  });
+  let settingsState = SettingsState::new(settings);
+
   #[allow(unused_mut)]
   let mut builder = tauri::Builder::default();
 
@@ -42,6 +132,17 @@ pub fn run() {
   builder
     .plugin(tauri_plugin_fs::init())
     .plugin(tauri_plugin_opener::init())
+    .manage(settingsState.clone())
+    .manage(JournalFollowState::default())
+    .manage(PrivilegedQueueState::default())
+    .setup(move |_app| {
+      let monitorService = MonitorService::new(settingsState);
+      if let Err(error) = monitorService.syncMonitorUnit() {
+        eprintln!("{}: {}", error.code, error.message);
+      }
+      monitorService.spawnMonitor();
+      Ok(())
+    })
     .invoke_handler(tauri::generate_handler![
       greet,
       getSystemServices,
@@ -65,10 +166,67 @@ pub fn run() {
       stopService,
       previewFile,
       getAllServices,
+      getServiceDetails,
+      getTimers,
       enableService,
       startService,
       enableSelectedServices,
-      openFile
+      restartService,
+      reloadService,
+      disableService,
+      maskService,
+      unmaskService,
+      startSelectedServices,
+      restartSelectedServices,
+      reloadSelectedServices,
+      disableSelectedServices,
+      maskSelectedServices,
+      unmaskSelectedServices,
+      openFile,
+      getSchedules,
+      saveSchedule,
+      removeSchedule,
+      runScheduleNow,
+      getDiskUsage,
+      getSettings,
+      updateSettings,
+      getProfiles,
+      saveProfile,
+      removeProfile,
+      runProfile,
+      getServiceJournal,
+      followServiceJournal,
+      stopFollowingJournal,
+      getBootAnalysis,
+      getFailedUnits,
+      getServiceSummary,
+      resetFailedService,
+      resetFailedSelectedServices,
+      getKnowledgeBase,
+      getServiceRecommendations,
+      getServiceSnapshots,
+      createServiceSnapshot,
+      removeServiceSnapshot,
+      diffServiceSnapshot,
+      restoreServiceSnapshot,
+      getElevationStatus,
+      elevate,
+      dropPrivileges,
+      queuePrivilegedOperations,
+      clearPrivilegedQueue,
+      runPrivilegedQueue,
+      getJournalUsage,
+      vacuumJournal,
+      getLogPage,
+      getLogFamilies,
+      applyLogRetention,
+      getPackageCache,
+      cleanPackageCache,
+      getOrphanPackages,
+      removeOrphanPackages,
+      getKernels,
+      removeKernels,
+      analyzeSystemLogs
     ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
@@ -2,5 +2,10 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+  let args: Vec<String> = std::env::args().collect();
+  if let Some(code) = cleanux_lib::runHeadless(&args) {
+    std::process::exit(code);
+  }
+
   cleanux_lib::run()
 }
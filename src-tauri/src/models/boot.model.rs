@@ -0,0 +1,35 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/// Duration of each boot phase in microseconds. Firmware and loader times are only known
+/// on EFI systems, and the initrd phase only when one was used.
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct BootTimesModel {
+  pub firmwareUs: Option<u64>,
+  pub loaderUs: Option<u64>,
+  pub kernelUs: u64,
+  pub initrdUs: Option<u64>,
+  pub userspaceUs: u64,
+  pub totalUs: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct UnitTimingModel {
+  pub name: String,
+  pub activationUs: u64,
+  /// Time the unit became active, relative to the start of userspace.
+  pub activatedAtUs: u64,
+  pub active: String,
+  pub unitFileState: String,
+  pub isService: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct BootAnalysisModel {
+  pub times: BootTimesModel,
+  pub blame: Vec<UnitTimingModel>,
+  pub criticalChain: Vec<UnitTimingModel>,
+}
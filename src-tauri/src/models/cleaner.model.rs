@@ -1,6 +1,10 @@
 /* sys lib */
 use serde::{Deserialize, Serialize};
 
+/* models */
+use crate::models::error_model::ItemResultModel;
+use crate::models::journal_model::JournalUsageModel;
+
 #[derive(Serialize, Deserialize, Clone)]
 #[allow(non_snake_case)]
 pub struct CacheFileModel {
@@ -18,12 +22,27 @@ pub struct TrashFileModel {
   pub deletedDate: String,
 }
 
+/// How a file under the log directory is cleaned.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum LogFileKind {
+  /// Still written by a running daemon, so it is truncated in place.
+  Active,
+  /// Rotated or compressed archive, which is deleted.
+  Rotated,
+  /// Login accounting database (`wtmp`, `btmp`, `lastlog`, ...), which is never touched.
+  Accounting,
+  /// systemd journal file, which only journald itself may shrink by vacuuming.
+  Journal,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[allow(non_snake_case)]
 pub struct LogFileModel {
   pub path: String,
   pub size: u64,
   pub modified: String,
+  pub kind: LogFileKind,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -41,3 +60,51 @@ pub struct ScanSummaryModel {
   pub totalSize: u64,
   pub fileCount: usize,
 }
+
+/// Log summary whose totals include the journal, which is also broken down on its own.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct LogSummaryModel {
+  #[serde(flatten)]
+  pub files: ScanSummaryModel,
+  pub journal: JournalUsageModel,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum CleanupCategory {
+  Cache,
+  Trash,
+  ThumbnailOrphans,
+  PipCache,
+  NpmCache,
+  CargoCache,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct CleanupRuleModel {
+  pub category: CleanupCategory,
+  #[serde(default)]
+  pub olderThanDays: Option<u32>,
+  #[serde(default)]
+  pub largerThanBytes: Option<u64>,
+  #[serde(default)]
+  pub excludedApps: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct CleanupPreviewModel {
+  pub category: CleanupCategory,
+  pub fileCount: usize,
+  pub totalSize: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct CleanupResultModel {
+  pub category: CleanupCategory,
+  pub removedFiles: usize,
+  pub freedBytes: u64,
+  pub errors: Vec<ItemResultModel>,
+}
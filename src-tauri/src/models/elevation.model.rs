@@ -0,0 +1,48 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/* models */
+use crate::models::error_model::BatchResultModel;
+use crate::models::journal_model::JournalVacuumModel;
+use crate::models::package_model::{PackageCacheCleanModel, PackageManager};
+use crate::models::system_model::ServiceAction;
+
+/// Operation waiting to run through the privileged helper. Service operations always
+/// target system units, since user units need no elevation.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PrivilegedOperationModel {
+  Service {
+    action: ServiceAction,
+    units: Vec<String>,
+  },
+  CleanLogs {
+    paths: Vec<String>,
+  },
+  VacuumJournal(JournalVacuumModel),
+  CleanPackageCache(PackageCacheCleanModel),
+  RemovePackages {
+    #[serde(default)]
+    manager: Option<PackageManager>,
+    packages: Vec<String>,
+  },
+  RemoveKernels {
+    versions: Vec<String>,
+  },
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ElevationStatusModel {
+  pub elevated: bool,
+  /// Seconds until the elevated session expires unless it is used again.
+  pub expiresIn: u64,
+  pub queue: Vec<PrivilegedOperationModel>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PrivilegedRunModel {
+  #[serde(flatten)]
+  pub batch: BatchResultModel,
+  pub operations: Vec<PrivilegedOperationModel>,
+}
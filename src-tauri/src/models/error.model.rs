@@ -0,0 +1,240 @@
+/* sys lib */
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::io;
+use std::process::Output;
+
+/* models */
+use crate::models::response_model::{DataValue, ResponseStatus};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCode {
+  PermissionDenied,
+  NotFound,
+  AlreadyExists,
+  Busy,
+  ElevationCancelled,
+  OutsideAllowedRoot,
+  InvalidInput,
+  CommandFailed,
+  PartialFailure,
+  Io,
+  Internal,
+}
+
+#[allow(non_snake_case)]
+impl ErrorCode {
+  pub fn fromIoKind(kind: io::ErrorKind) -> Self {
+    match kind {
+      io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => {
+        ErrorCode::PermissionDenied
+      }
+      io::ErrorKind::NotFound => ErrorCode::NotFound,
+      io::ErrorKind::AlreadyExists => ErrorCode::AlreadyExists,
+      io::ErrorKind::ResourceBusy | io::ErrorKind::ExecutableFileBusy => ErrorCode::Busy,
+      io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => ErrorCode::InvalidInput,
+      _ => ErrorCode::Io,
+    }
+  }
+
+  /// Exit status used by the headless CLI modes so timers and scripts can branch on it.
+  pub fn exitCode(&self) -> i32 {
+    match self {
+      ErrorCode::InvalidInput => 2,
+      ErrorCode::NotFound => 3,
+      ErrorCode::PermissionDenied | ErrorCode::OutsideAllowedRoot => 4,
+      ErrorCode::ElevationCancelled => 5,
+      ErrorCode::Busy => 6,
+      ErrorCode::PartialFailure => 7,
+      _ => 1,
+    }
+  }
+}
+
+/// Writes the serialized name, such as `notFound`, so it always matches the JSON `code`.
+impl fmt::Display for ErrorCode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.serialize(f)
+  }
+}
+
+/// Failure returned by every command. It serializes with the same `status`/`message`/`data`
+/// shape as `ResponseModel`, plus a machine-readable `code`.
+#[derive(Debug, Clone)]
+pub struct AppError {
+  pub code: ErrorCode,
+  pub message: String,
+  pub data: DataValue,
+}
+
+#[allow(non_snake_case)]
+impl AppError {
+  pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+    Self {
+      code,
+      message: message.into(),
+      data: DataValue::String("".to_string()),
+    }
+  }
+
+  pub fn notFound(message: impl Into<String>) -> Self {
+    Self::new(ErrorCode::NotFound, message)
+  }
+
+  pub fn invalidInput(message: impl Into<String>) -> Self {
+    Self::new(ErrorCode::InvalidInput, message)
+  }
+
+  pub fn internal(message: impl Into<String>) -> Self {
+    Self::new(ErrorCode::Internal, message)
+  }
+
+  pub fn io(context: impl fmt::Display, error: &io::Error) -> Self {
+    Self::new(
+      ErrorCode::fromIoKind(error.kind()),
+      format!("{}: {}", context, error),
+    )
+  }
+
+  pub fn spawn(program: &str, error: &io::Error) -> Self {
+    let code = match error.kind() {
+      io::ErrorKind::NotFound => ErrorCode::NotFound,
+      kind => ErrorCode::fromIoKind(kind),
+    };
+    Self::new(code, format!("Failed to run {}: {}", program, error))
+  }
+
+  pub fn command(context: impl fmt::Display, output: &Output) -> Self {
+    Self::new(
+      ErrorCode::CommandFailed,
+      format!(
+        "{}: {}",
+        context,
+        String::from_utf8_lossy(&output.stderr).trim()
+      ),
+    )
+  }
+
+  pub fn withData(mut self, data: DataValue) -> Self {
+    self.data = data;
+    self
+  }
+}
+
+impl fmt::Display for AppError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.message)
+  }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("AppError", 4)?;
+    state.serialize_field("status", &ResponseStatus::Error)?;
+    state.serialize_field("code", &self.code)?;
+    state.serialize_field("message", &self.message)?;
+    state.serialize_field("data", &self.data)?;
+    state.end()
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ItemResultModel {
+  pub item: String,
+  pub success: bool,
+  #[serde(default)]
+  pub code: Option<ErrorCode>,
+  #[serde(default)]
+  pub message: Option<String>,
+}
+
+#[allow(non_snake_case)]
+impl ItemResultModel {
+  pub fn succeeded(item: impl Into<String>) -> Self {
+    Self {
+      item: item.into(),
+      success: true,
+      code: None,
+      message: None,
+    }
+  }
+
+  pub fn failed(item: impl Into<String>, error: AppError) -> Self {
+    Self {
+      item: item.into(),
+      success: false,
+      code: Some(error.code),
+      message: Some(error.message),
+    }
+  }
+
+  pub fn toResult(self) -> Result<(), AppError> {
+    if self.success {
+      return Ok(());
+    }
+    Err(AppError::new(
+      self.code.unwrap_or(ErrorCode::CommandFailed),
+      self.message.unwrap_or_default(),
+    ))
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct BatchResultModel {
+  pub succeeded: usize,
+  pub failed: usize,
+  pub items: Vec<ItemResultModel>,
+}
+
+#[allow(non_snake_case)]
+impl BatchResultModel {
+  pub fn record(&mut self, item: impl Into<String>, result: Result<(), AppError>) {
+    match result {
+      Ok(()) => self.push(ItemResultModel::succeeded(item)),
+      Err(error) => self.push(ItemResultModel::failed(item, error)),
+    }
+  }
+
+  pub fn push(&mut self, item: ItemResultModel) {
+    if item.success {
+      self.succeeded += 1;
+    } else {
+      self.failed += 1;
+    }
+    self.items.push(item);
+  }
+
+  /// Error describing the failed items, or `None` when every item succeeded. A batch in
+  /// which all items failed for the same reason keeps that reason's code.
+  pub fn toError(&self, action: &str) -> Option<AppError> {
+    if self.failed == 0 {
+      return None;
+    }
+
+    let mut failures = self.items.iter().filter(|item| !item.success);
+    let first = failures.next();
+    let firstCode = first.and_then(|item| item.code);
+    let mut message = format!(
+      "{} {} of {} items",
+      action,
+      self.succeeded,
+      self.succeeded + self.failed
+    );
+    let code = match firstCode {
+      Some(code) if self.succeeded == 0 && failures.all(|item| item.code == Some(code)) => {
+        if let Some(reason) = first.and_then(|item| item.message.as_ref()) {
+          message = format!("{}: {}", message, reason);
+        }
+        code
+      }
+      _ => ErrorCode::PartialFailure,
+    };
+    let data = DataValue::Object(serde_json::to_value(self).unwrap_or_default());
+
+    Some(AppError::new(code, message).withData(data))
+  }
+}
@@ -0,0 +1,72 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/* models */
+use crate::models::system_model::ServiceScope;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct JournalQueryModel {
+  pub unit: String,
+  #[serde(default)]
+  pub scope: ServiceScope,
+  #[serde(default)]
+  pub lines: Option<usize>,
+  /// Most verbose syslog priority to include, from 0 (emerg) to 7 (debug).
+  #[serde(default)]
+  pub priority: Option<u8>,
+  #[serde(default)]
+  pub since: Option<String>,
+  #[serde(default)]
+  pub until: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct JournalEntryModel {
+  pub cursor: String,
+  pub timestamp: String,
+  pub priority: u8,
+  pub identifier: String,
+  pub pid: Option<u32>,
+  pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct JournalEventModel {
+  pub followId: u32,
+  pub entry: JournalEntryModel,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct JournalUsageModel {
+  pub totalSize: u64,
+  pub fileCount: usize,
+  /// Archived journal files, the only ones vacuuming removes.
+  pub archivedSize: u64,
+  pub archivedCount: usize,
+}
+
+/// Limits to vacuum the journal down to; archived files are removed until all are met.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct JournalVacuumModel {
+  #[serde(default)]
+  pub maxSize: Option<u64>,
+  #[serde(default)]
+  pub maxAgeDays: Option<u32>,
+  #[serde(default)]
+  pub maxFiles: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct JournalVacuumResultModel {
+  pub before: JournalUsageModel,
+  pub after: JournalUsageModel,
+  pub freedBytes: u64,
+  /// What journalctl reported it removed.
+  pub output: String,
+}
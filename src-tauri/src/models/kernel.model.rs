@@ -0,0 +1,20 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/// An installed kernel, found through its modules directory or its image in `/boot`.
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct KernelModel {
+  /// Release as `uname -r` prints it, such as `6.8.0-45-generic`.
+  pub version: String,
+  pub running: bool,
+  pub latest: bool,
+  /// Running and latest kernels are never removed.
+  pub protected: bool,
+  pub bootFiles: Vec<String>,
+  pub bootSize: u64,
+  pub modulesSize: u64,
+  pub totalSize: u64,
+  /// Packages owning the kernel's files, which removing it uninstalls.
+  pub packages: Vec<String>,
+}
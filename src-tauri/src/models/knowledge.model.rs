@@ -0,0 +1,67 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/* models */
+use crate::models::system_model::SystemServiceModel;
+
+/// How much breaks when the unit is disabled, from nothing noticeable to core desktop
+/// functionality.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceRisk {
+  Safe,
+  Low,
+  Medium,
+  High,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ServiceKnowledgeModel {
+  /// Unit name; `name@.service` matches every instance of a template.
+  pub unit: String,
+  pub description: String,
+  pub risk: ServiceRisk,
+  #[serde(default)]
+  pub features: Vec<String>,
+  /// Device class under `/sys/class`, such as `bluetooth`, served by the unit. While such a
+  /// device is present the unit is at least medium risk.
+  #[serde(default)]
+  pub deviceClass: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServicePresetModel {
+  pub id: String,
+  pub name: String,
+  #[serde(default)]
+  pub description: String,
+  pub features: Vec<String>,
+  /// Low-risk units on this system that the preset would disable.
+  #[serde(default)]
+  pub units: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct KnowledgeBaseModel {
+  #[serde(default)]
+  pub version: u32,
+  #[serde(default)]
+  pub services: Vec<ServiceKnowledgeModel>,
+  #[serde(default)]
+  pub presets: Vec<ServicePresetModel>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServiceRecommendationModel {
+  #[serde(flatten)]
+  pub unit: SystemServiceModel,
+  pub knowledge: ServiceKnowledgeModel,
+  pub recommended: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServiceRecommendationsModel {
+  pub recommendations: Vec<ServiceRecommendationModel>,
+  pub presets: Vec<ServicePresetModel>,
+}
@@ -0,0 +1,157 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/* models */
+use crate::models::cleaner_model::LogFileModel;
+use crate::models::error_model::{BatchResultModel, ItemResultModel};
+
+/// Severity guessed from the wording of a plain-text log line.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum LogSeverity {
+  Critical,
+  Error,
+  Warning,
+  Info,
+  Debug,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct LogSeverityCountsModel {
+  pub critical: u64,
+  pub error: u64,
+  pub warning: u64,
+  pub info: u64,
+  pub debug: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct LogMessageCountModel {
+  pub source: String,
+  /// Message with every run of digits replaced by `#`, so repeats that differ only in
+  /// pids, counters or addresses are counted together.
+  pub message: String,
+  pub severity: LogSeverity,
+  pub count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct LogSourceCountModel {
+  /// Program from the syslog tag, or the log's own name for logs without one.
+  pub source: String,
+  pub lines: u64,
+  pub bytes: u64,
+  pub errors: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct LogAnalysisModel {
+  pub analyzedFiles: usize,
+  /// Files that could not be read, such as logs readable only by root or binary files.
+  pub skippedFiles: usize,
+  /// Compressed logs that could not be decompressed, such as when the tool for their
+  /// format is not installed.
+  pub failedFiles: Vec<ItemResultModel>,
+  /// Files only partly analyzed because they exceed `logs.analysisMaxBytes`.
+  pub truncatedFiles: usize,
+  pub lines: u64,
+  pub bytes: u64,
+  pub severities: LogSeverityCountsModel,
+  pub topMessages: Vec<LogMessageCountModel>,
+  pub topSources: Vec<LogSourceCountModel>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum LogPageDirection {
+  /// Lines ending before the offset, for reading back from the end of a log.
+  #[default]
+  Backward,
+  /// Lines starting at or after the offset.
+  Forward,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct LogPageQueryModel {
+  pub path: String,
+  /// Byte offset to page from, as returned in a page's `start` or `end`. Defaults to the
+  /// end of the log, or its start when paging forward.
+  #[serde(default)]
+  pub offset: Option<u64>,
+  #[serde(default)]
+  pub direction: LogPageDirection,
+  #[serde(default)]
+  pub lines: Option<usize>,
+  /// Regular expression a line must match to be returned.
+  #[serde(default)]
+  pub pattern: Option<String>,
+  #[serde(default)]
+  pub ignoreCase: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct LogLineModel {
+  /// Byte offset of the line in the uncompressed log.
+  pub offset: u64,
+  pub text: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct LogPageModel {
+  pub path: String,
+  pub compressed: bool,
+  /// Uncompressed size, unknown for a compressed log that was not read to the end.
+  pub size: Option<u64>,
+  /// Byte range scanned for this page; the previous page ends at `start` and the next one
+  /// begins at `end`.
+  pub start: u64,
+  pub end: u64,
+  pub hasBefore: bool,
+  pub hasAfter: bool,
+  /// Set when a search stopped at the scan limit before filling the page; paging on from
+  /// `start` or `end` continues it.
+  pub scanLimited: bool,
+  pub lines: Vec<LogLineModel>,
+}
+
+/// A log and its rotated copies, such as `syslog`, `syslog.1` and `syslog.2.gz`.
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct LogFamilyModel {
+  /// Path of the log the rotations come from, whether or not it still exists.
+  pub base: String,
+  pub active: Option<LogFileModel>,
+  /// Rotated copies, newest first.
+  pub rotations: Vec<LogFileModel>,
+  pub totalSize: u64,
+  pub rotatedSize: u64,
+}
+
+/// Which rotated copies to delete; a rotation goes when either limit says so. Active logs
+/// are never touched, so logrotate keeps managing them.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct LogRetentionModel {
+  /// Rotations kept per family, newest first.
+  #[serde(default)]
+  pub keepRotations: Option<usize>,
+  #[serde(default)]
+  pub maxAgeDays: Option<u32>,
+  /// Lists the rotations the policy would delete without deleting them.
+  #[serde(default)]
+  pub dryRun: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct LogRetentionResultModel {
+  #[serde(flatten)]
+  pub batch: BatchResultModel,
+  pub candidates: Vec<LogFileModel>,
+  pub freedBytes: u64,
+}
@@ -2,14 +2,85 @@
 #[path = "response.model.rs"]
 pub mod response_model;
 
+#[path = "error.model.rs"]
+pub mod error_model;
+
 #[path = "system.model.rs"]
 pub mod system_model;
 
 #[path = "cleaner.model.rs"]
 pub mod cleaner_model;
 
+#[path = "schedule.model.rs"]
+pub mod schedule_model;
+
+#[path = "monitor.model.rs"]
+pub mod monitor_model;
+
+#[path = "settings.model.rs"]
+pub mod settings_model;
+
+#[path = "profile.model.rs"]
+pub mod profile_model;
+
+#[path = "journal.model.rs"]
+pub mod journal_model;
+
+#[path = "boot.model.rs"]
+pub mod boot_model;
+
+#[path = "knowledge.model.rs"]
+pub mod knowledge_model;
+
+#[path = "snapshot.model.rs"]
+pub mod snapshot_model;
+
+#[path = "elevation.model.rs"]
+pub mod elevation_model;
+
+#[path = "log.model.rs"]
+pub mod log_model;
+
+#[path = "package.model.rs"]
+pub mod package_model;
+
+#[path = "kernel.model.rs"]
+pub mod kernel_model;
+
+pub use boot_model::{BootAnalysisModel, BootTimesModel, UnitTimingModel};
 pub use cleaner_model::{
-  CacheFileModel, LargeFileModel, LogFileModel, ScanSummaryModel, TrashFileModel,
+  CacheFileModel, CleanupCategory, CleanupPreviewModel, CleanupResultModel, CleanupRuleModel,
+  LargeFileModel, LogFileKind, LogFileModel, LogSummaryModel, ScanSummaryModel, TrashFileModel,
+};
+pub use elevation_model::{ElevationStatusModel, PrivilegedOperationModel, PrivilegedRunModel};
+pub use error_model::{AppError, BatchResultModel, ErrorCode, ItemResultModel};
+pub use journal_model::{
+  JournalEntryModel, JournalEventModel, JournalQueryModel, JournalUsageModel, JournalVacuumModel,
+  JournalVacuumResultModel,
+};
+pub use kernel_model::KernelModel;
+pub use knowledge_model::{
+  KnowledgeBaseModel, ServiceKnowledgeModel, ServicePresetModel, ServiceRecommendationModel,
+  ServiceRecommendationsModel, ServiceRisk,
 };
+pub use log_model::{
+  LogAnalysisModel, LogFamilyModel, LogLineModel, LogMessageCountModel, LogPageDirection,
+  LogPageModel, LogPageQueryModel, LogRetentionModel, LogRetentionResultModel, LogSeverity,
+  LogSeverityCountsModel, LogSourceCountModel,
+};
+pub use monitor_model::{DiskUsageModel, MonitorConfigModel};
+pub use package_model::{
+  OrphanPackageModel, OrphanPackagesModel, PackageCacheCleanModel, PackageCacheCleanResultModel,
+  PackageCacheModel, PackageManager,
+};
+pub use profile_model::{CleanupProfileModel, ProfileRunModel};
 pub use response_model::{DataValue, ResponseModel, ResponseStatus};
-pub use system_model::SystemServiceModel;
+pub use schedule_model::{ScheduleModel, ScheduleRunModel, ScheduleStatusModel};
+pub use settings_model::{SettingsErrorModel, SettingsModel};
+pub use snapshot_model::{
+  ServiceSnapshotModel, SnapshotRestoreModel, UnitStateChangeModel, UnitStateModel,
+};
+pub use system_model::{
+  FailedUnitModel, ServiceAction, ServiceBatchResultModel, ServiceDetailsModel, ServiceScope,
+  ServiceSummaryModel, SystemServiceModel, TimerModel,
+};
@@ -0,0 +1,32 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+#[allow(non_snake_case)]
+pub struct MonitorConfigModel {
+  pub enabled: bool,
+  pub minFreePercent: u8,
+  pub intervalMinutes: u32,
+  pub profileId: Option<String>,
+}
+
+impl Default for MonitorConfigModel {
+  fn default() -> Self {
+    Self {
+      enabled: false,
+      minFreePercent: 10,
+      intervalMinutes: 15,
+      profileId: None,
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct DiskUsageModel {
+  pub path: String,
+  pub totalBytes: u64,
+  pub freeBytes: u64,
+  pub freePercent: f64,
+}
@@ -0,0 +1,82 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum PackageManager {
+  Apt,
+  Dnf,
+  Pacman,
+  Zypper,
+}
+
+impl PackageManager {
+  pub fn parse(name: &str) -> Option<Self> {
+    match name {
+      "apt" => Some(PackageManager::Apt),
+      "dnf" => Some(PackageManager::Dnf),
+      "pacman" => Some(PackageManager::Pacman),
+      "zypper" => Some(PackageManager::Zypper),
+      _ => None,
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      PackageManager::Apt => "apt",
+      PackageManager::Dnf => "dnf",
+      PackageManager::Pacman => "pacman",
+      PackageManager::Zypper => "zypper",
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct PackageCacheModel {
+  pub manager: PackageManager,
+  pub cacheDirs: Vec<String>,
+  pub totalSize: u64,
+  /// Downloaded package archives, as opposed to metadata and partial downloads.
+  pub packageCount: usize,
+  pub packageSize: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct PackageCacheCleanModel {
+  /// Defaults to the package manager detected on this system.
+  #[serde(default)]
+  pub manager: Option<PackageManager>,
+  /// Versions of each package to keep; only pacman can keep some, through paccache.
+  /// Without it each tool cleans the way it does by default.
+  #[serde(default)]
+  pub keepVersions: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct PackageCacheCleanResultModel {
+  pub before: PackageCacheModel,
+  pub after: PackageCacheModel,
+  pub freedBytes: u64,
+  pub output: String,
+}
+
+/// An installed package that was pulled in as a dependency and that nothing requires
+/// anymore.
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct OrphanPackageModel {
+  pub name: String,
+  pub version: String,
+  pub installedSize: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct OrphanPackagesModel {
+  pub manager: PackageManager,
+  pub packages: Vec<OrphanPackageModel>,
+  pub totalSize: u64,
+}
@@ -0,0 +1,32 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/* models */
+use crate::models::cleaner_model::{CleanupPreviewModel, CleanupResultModel, CleanupRuleModel};
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct CleanupProfileModel {
+  pub id: String,
+  pub name: String,
+  #[serde(default)]
+  pub description: String,
+  pub rules: Vec<CleanupRuleModel>,
+  #[serde(default)]
+  pub builtIn: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ProfileRunModel {
+  pub profileId: String,
+  pub dryRun: bool,
+  /// Identifies the previewed files; pass it back to `runProfile` to remove them.
+  pub planToken: String,
+  pub preview: Vec<CleanupPreviewModel>,
+  pub results: Vec<CleanupResultModel>,
+  pub totalFiles: usize,
+  pub totalSize: u64,
+  pub removedFiles: usize,
+  pub freedBytes: u64,
+}
@@ -0,0 +1,39 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/* models */
+use crate::models::cleaner_model::CleanupResultModel;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ScheduleModel {
+  pub id: String,
+  pub name: String,
+  pub onCalendar: String,
+  pub profileId: String,
+  pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ScheduleRunModel {
+  pub scheduleId: String,
+  pub profileId: String,
+  pub startedAt: String,
+  pub finishedAt: String,
+  pub success: bool,
+  pub removedFiles: usize,
+  pub freedBytes: u64,
+  pub results: Vec<CleanupResultModel>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ScheduleStatusModel {
+  pub schedule: ScheduleModel,
+  pub installed: bool,
+  pub timerActive: bool,
+  pub nextRun: Option<String>,
+  pub lastTrigger: Option<String>,
+  pub lastRun: Option<ScheduleRunModel>,
+}
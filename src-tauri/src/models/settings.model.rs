@@ -0,0 +1,147 @@
+/* sys lib */
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/* models */
+use crate::models::monitor_model::MonitorConfigModel;
+use crate::models::profile_model::CleanupProfileModel;
+use crate::models::schedule_model::ScheduleModel;
+
+pub const SETTINGS_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+#[allow(non_snake_case)]
+pub struct SettingsModel {
+  pub version: u32,
+  pub cache: CacheSettingsModel,
+  pub logs: LogSettingsModel,
+  pub largeFiles: LargeFilesSettingsModel,
+  pub monitor: MonitorConfigModel,
+  pub profiles: Vec<CleanupProfileModel>,
+  pub schedules: Vec<ScheduleModel>,
+}
+
+impl Default for SettingsModel {
+  fn default() -> Self {
+    Self {
+      version: SETTINGS_VERSION,
+      cache: CacheSettingsModel::default(),
+      logs: LogSettingsModel::default(),
+      largeFiles: LargeFilesSettingsModel::default(),
+      monitor: MonitorConfigModel::default(),
+      profiles: Vec::new(),
+      schedules: Vec::new(),
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+#[allow(non_snake_case)]
+pub struct CacheSettingsModel {
+  pub cacheDir: Option<String>,
+  pub maxDepth: usize,
+  pub listLimit: usize,
+  pub summaryLimit: usize,
+}
+
+impl Default for CacheSettingsModel {
+  fn default() -> Self {
+    Self {
+      cacheDir: None,
+      maxDepth: 4,
+      listLimit: 1000,
+      summaryLimit: 2000,
+    }
+  }
+}
+
+#[allow(non_snake_case)]
+impl CacheSettingsModel {
+  pub fn resolveCacheDir(&self) -> Option<PathBuf> {
+    match &self.cacheDir {
+      Some(dir) => expand_home(dir),
+      None => dirs::cache_dir(),
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+#[allow(non_snake_case)]
+pub struct LogSettingsModel {
+  pub logDir: String,
+  pub maxDepth: usize,
+  pub summaryMaxDepth: usize,
+  pub listLimit: usize,
+  /// Bytes read from each log when analyzing contents; larger logs are analyzed in part.
+  pub analysisMaxBytes: u64,
+}
+
+impl Default for LogSettingsModel {
+  fn default() -> Self {
+    Self {
+      logDir: "/var/log".to_string(),
+      maxDepth: 3,
+      summaryMaxDepth: 2,
+      listLimit: 500,
+      analysisMaxBytes: 64 * 1024 * 1024,
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+#[allow(non_snake_case)]
+pub struct LargeFilesSettingsModel {
+  pub thresholdBytes: u64,
+  pub roots: Vec<String>,
+  pub maxDepth: usize,
+  pub perRootLimit: usize,
+  pub listLimit: usize,
+}
+
+impl Default for LargeFilesSettingsModel {
+  fn default() -> Self {
+    Self {
+      thresholdBytes: 100 * 1024 * 1024,
+      roots: vec![
+        "~/Downloads".to_string(),
+        "~/Documents".to_string(),
+        "~/Videos".to_string(),
+        "~/Pictures".to_string(),
+        "~/Desktop".to_string(),
+      ],
+      maxDepth: 3,
+      perRootLimit: 50,
+      listLimit: 200,
+    }
+  }
+}
+
+#[allow(non_snake_case)]
+impl LargeFilesSettingsModel {
+  pub fn resolveRoots(&self) -> Vec<PathBuf> {
+    self
+      .roots
+      .iter()
+      .filter_map(|root| expand_home(root))
+      .collect()
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SettingsErrorModel {
+  pub field: String,
+  pub message: String,
+}
+
+pub fn expand_home(path: &str) -> Option<PathBuf> {
+  match path.strip_prefix("~/") {
+    Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+    None if path == "~" => dirs::home_dir(),
+    None => Some(PathBuf::from(path)),
+  }
+}
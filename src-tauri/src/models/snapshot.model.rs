@@ -0,0 +1,41 @@
+/* sys lib */
+use serde::{Deserialize, Serialize};
+
+/* models */
+use crate::models::error_model::BatchResultModel;
+use crate::models::system_model::ServiceScope;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[allow(non_snake_case)]
+pub struct UnitStateModel {
+  pub name: String,
+  pub unitFileState: String,
+  pub active: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ServiceSnapshotModel {
+  pub id: String,
+  pub name: String,
+  #[serde(default)]
+  pub scope: ServiceScope,
+  pub createdAt: String,
+  pub units: Vec<UnitStateModel>,
+}
+
+/// Difference between a unit's state in a snapshot and now. A unit that no longer exists
+/// has no current state.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UnitStateChangeModel {
+  pub name: String,
+  pub snapshot: UnitStateModel,
+  pub current: Option<UnitStateModel>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SnapshotRestoreModel {
+  #[serde(flatten)]
+  pub batch: BatchResultModel,
+  pub changes: Vec<UnitStateChangeModel>,
+}
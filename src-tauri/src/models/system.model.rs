@@ -3,16 +3,83 @@ use serde::{Deserialize, Serialize};
 
 /* models */
 use crate::models::cleaner_model::{CacheFileModel, LargeFileModel, LogFileModel, TrashFileModel};
+use crate::models::error_model::BatchResultModel;
+use crate::models::journal_model::JournalEntryModel;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceScope {
+  #[default]
+  System,
+  User,
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 #[allow(non_snake_case)]
 pub struct SystemServiceModel {
   pub name: String,
+  pub scope: ServiceScope,
   pub description: String,
+  pub load: String,
+  pub active: String,
   pub status: String,
   pub isRunning: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ServiceDetailsModel {
+  #[serde(flatten)]
+  pub unit: SystemServiceModel,
+  pub fragmentPath: String,
+  pub unitFileState: String,
+  pub unitFilePreset: String,
+  pub mainPid: Option<u32>,
+  pub memoryBytes: Option<u64>,
+  pub cpuNanoseconds: Option<u64>,
+  pub startedAt: Option<String>,
+  pub restartCount: u32,
+  pub wants: Vec<String>,
+  pub requires: Vec<String>,
+  pub wantedBy: Vec<String>,
+  /// Active units that systemd would stop along with this one.
+  pub stoppedWith: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct FailedUnitModel {
+  #[serde(flatten)]
+  pub unit: SystemServiceModel,
+  /// systemd's reason for the failure, such as `exit-code`, `signal` or `timeout`.
+  pub result: String,
+  pub exitStatus: Option<i32>,
+  pub exitSignal: Option<i32>,
+  pub failedAt: Option<String>,
+  pub recentLogs: Vec<JournalEntryModel>,
+  /// Why the failure details could not be read; the unit is still listed without them.
+  #[serde(default)]
+  pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct TimerModel {
+  #[serde(flatten)]
+  pub unit: SystemServiceModel,
+  /// Unit the timer activates, usually the service of the same name.
+  pub triggers: String,
+  pub unitFileState: String,
+  pub nextElapse: Option<String>,
+  pub lastTrigger: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServiceSummaryModel {
+  pub running: usize,
+  pub failed: usize,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[allow(non_snake_case, dead_code)]
 pub struct SystemDataModel {
@@ -22,3 +89,48 @@ pub struct SystemDataModel {
   pub logFiles: Vec<LogFileModel>,
   pub largeFiles: Vec<LargeFileModel>,
 }
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServiceBatchResultModel {
+  #[serde(flatten)]
+  pub batch: BatchResultModel,
+  pub units: Vec<SystemServiceModel>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ServiceAction {
+  Start,
+  Stop,
+  Restart,
+  Reload,
+  Enable,
+  Disable,
+  Mask,
+  Unmask,
+  ResetFailed,
+}
+
+#[allow(non_snake_case)]
+impl ServiceAction {
+  pub fn changesUnitFiles(&self) -> bool {
+    matches!(
+      self,
+      ServiceAction::Enable | ServiceAction::Disable | ServiceAction::Mask | ServiceAction::Unmask
+    )
+  }
+
+  pub fn pastTense(&self) -> &'static str {
+    match self {
+      ServiceAction::Start => "started",
+      ServiceAction::Stop => "stopped",
+      ServiceAction::Restart => "restarted",
+      ServiceAction::Reload => "reloaded",
+      ServiceAction::Enable => "enabled",
+      ServiceAction::Disable => "disabled",
+      ServiceAction::Mask => "masked",
+      ServiceAction::Unmask => "unmasked",
+      ServiceAction::ResetFailed => "reset",
+    }
+  }
+}
@@ -0,0 +1,12 @@
+/* controllers */
+use crate::controllers::boot_controller::BootController;
+
+/* models */
+use crate::models::{AppError, ResponseModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getBootAnalysis() -> Result<ResponseModel, AppError> {
+  let controller = BootController::new();
+  controller.getBootAnalysis()
+}
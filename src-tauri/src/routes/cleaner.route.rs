@@ -1,96 +1,158 @@
+/* tauri */
+use tauri::State;
+
 /* controllers */
 use crate::controllers::cleaner_controller::CleanerController;
 
+/* services */
+use crate::services::settings_service::SettingsState;
+
 /* models */
-use crate::models::ResponseModel;
+use crate::models::{AppError, JournalVacuumModel, LogRetentionModel, ResponseModel};
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getCacheFiles() -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn getCacheFiles(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.getCacheFiles()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getTrashFiles() -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn getTrashFiles(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.getTrashFiles()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getSystemLogs() -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn getSystemLogs(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.getSystemLogs()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getLargeFiles() -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn analyzeSystemLogs(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
+  controller.analyzeSystemLogs()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getLogFamilies(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
+  controller.getLogFamilies()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn applyLogRetention(
+  settings: State<'_, SettingsState>,
+  retention: LogRetentionModel,
+) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
+  controller.applyLogRetention(retention)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getLargeFiles(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.getLargeFiles()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn previewFile(path: String) -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn previewFile(
+  settings: State<'_, SettingsState>,
+  path: String,
+) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.previewFile(path)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn clearSelectedCacheFiles(paths: Vec<String>) -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn clearSelectedCacheFiles(
+  settings: State<'_, SettingsState>,
+  paths: Vec<String>,
+) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.clearSelectedCacheFiles(paths)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn clearSelectedTrashFiles(paths: Vec<String>) -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn clearSelectedTrashFiles(
+  settings: State<'_, SettingsState>,
+  paths: Vec<String>,
+) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.clearSelectedTrashFiles(paths)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn clearSelectedLogFiles(paths: Vec<String>) -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn clearSelectedLogFiles(
+  settings: State<'_, SettingsState>,
+  paths: Vec<String>,
+) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.clearSelectedLogFiles(paths)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn clearSelectedLargeFiles(paths: Vec<String>) -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn clearSelectedLargeFiles(
+  settings: State<'_, SettingsState>,
+  paths: Vec<String>,
+) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.clearSelectedLargeFiles(paths)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn clearTrash() -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn clearTrash(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.clearTrash()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn clearCache() -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn clearCache(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.clearCache()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn clearAllLogs() -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn clearAllLogs(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.clearAllLogs()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn clearAllLargeFiles() -> Result<ResponseModel, ResponseModel> {
-  let controller = CleanerController::new();
+pub fn clearAllLargeFiles(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
   controller.clearAllLargeFiles()
 }
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getJournalUsage(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
+  controller.getJournalUsage()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn vacuumJournal(
+  settings: State<'_, SettingsState>,
+  vacuum: JournalVacuumModel,
+) -> Result<ResponseModel, AppError> {
+  let controller = CleanerController::new(settings.get());
+  controller.vacuumJournal(vacuum)
+}
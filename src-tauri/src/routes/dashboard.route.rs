@@ -1,40 +1,69 @@
+/* tauri */
+use tauri::State;
+
 /* controllers */
 use crate::controllers::dashboard_controller::DashboardController;
 
+/* services */
+use crate::services::settings_service::SettingsState;
+
 /* models */
-use crate::models::ResponseModel;
+use crate::models::{AppError, ResponseModel, ServiceScope};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getSystemServices(
+  settings: State<'_, SettingsState>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = DashboardController::new(settings.get());
+  controller.getRunningServices(scope.unwrap_or_default())
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getFailedUnits(
+  settings: State<'_, SettingsState>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = DashboardController::new(settings.get());
+  controller.getFailedUnits(scope.unwrap_or_default())
+}
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getSystemServices() -> Result<ResponseModel, ResponseModel> {
-  let controller = DashboardController::new();
-  controller.getRunningServices()
+pub fn getServiceSummary(
+  settings: State<'_, SettingsState>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = DashboardController::new(settings.get());
+  controller.getServiceSummary(scope.unwrap_or_default())
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getCacheSummary() -> Result<ResponseModel, ResponseModel> {
-  let controller = DashboardController::new();
+pub fn getCacheSummary(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = DashboardController::new(settings.get());
   controller.getCacheSummary()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getTrashSummary() -> Result<ResponseModel, ResponseModel> {
-  let controller = DashboardController::new();
+pub fn getTrashSummary(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = DashboardController::new(settings.get());
   controller.getTrashSummary()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getLogSummary() -> Result<ResponseModel, ResponseModel> {
-  let controller = DashboardController::new();
+pub fn getLogSummary(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = DashboardController::new(settings.get());
   controller.getLogSummary()
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getLargeFilesSummary() -> Result<ResponseModel, ResponseModel> {
-  let controller = DashboardController::new();
+pub fn getLargeFilesSummary(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = DashboardController::new(settings.get());
   controller.getLargeFilesSummary()
 }
@@ -0,0 +1,62 @@
+/* tauri */
+use tauri::State;
+
+/* controllers */
+use crate::controllers::elevation_controller::ElevationController;
+
+/* services */
+use crate::services::elevation_service::PrivilegedQueueState;
+
+/* models */
+use crate::models::{AppError, PrivilegedOperationModel, ResponseModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getElevationStatus(
+  queue: State<'_, PrivilegedQueueState>,
+) -> Result<ResponseModel, AppError> {
+  let controller = ElevationController::new(queue.inner().clone());
+  controller.getElevationStatus()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn elevate(queue: State<'_, PrivilegedQueueState>) -> Result<ResponseModel, AppError> {
+  let controller = ElevationController::new(queue.inner().clone());
+  controller.elevate()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn dropPrivileges(queue: State<'_, PrivilegedQueueState>) -> Result<ResponseModel, AppError> {
+  let controller = ElevationController::new(queue.inner().clone());
+  controller.dropPrivileges()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn queuePrivilegedOperations(
+  queue: State<'_, PrivilegedQueueState>,
+  operations: Vec<PrivilegedOperationModel>,
+) -> Result<ResponseModel, AppError> {
+  let controller = ElevationController::new(queue.inner().clone());
+  controller.queuePrivilegedOperations(operations)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn clearPrivilegedQueue(
+  queue: State<'_, PrivilegedQueueState>,
+) -> Result<ResponseModel, AppError> {
+  let controller = ElevationController::new(queue.inner().clone());
+  controller.clearPrivilegedQueue()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn runPrivilegedQueue(
+  queue: State<'_, PrivilegedQueueState>,
+) -> Result<ResponseModel, AppError> {
+  let controller = ElevationController::new(queue.inner().clone());
+  controller.runPrivilegedQueue()
+}
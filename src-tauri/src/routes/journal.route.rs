@@ -0,0 +1,48 @@
+/* tauri */
+use tauri::{AppHandle, Emitter, State};
+
+/* controllers */
+use crate::controllers::journal_controller::JournalController;
+
+/* services */
+use crate::services::journal_service::JournalFollowState;
+
+/* models */
+use crate::models::{AppError, JournalEventModel, JournalQueryModel, ResponseModel};
+
+const JOURNAL_ENTRY_EVENT: &str = "journal-entry";
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getServiceJournal(
+  follows: State<'_, JournalFollowState>,
+  query: JournalQueryModel,
+) -> Result<ResponseModel, AppError> {
+  let controller = JournalController::new(follows.inner().clone());
+  controller.getServiceJournal(query)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn followServiceJournal(
+  app: AppHandle,
+  follows: State<'_, JournalFollowState>,
+  query: JournalQueryModel,
+) -> Result<ResponseModel, AppError> {
+  let controller = JournalController::new(follows.inner().clone());
+  controller.followServiceJournal(query, move |followId, entry| {
+    if let Err(e) = app.emit(JOURNAL_ENTRY_EVENT, JournalEventModel { followId, entry }) {
+      eprintln!("Failed to emit journal entry: {}", e);
+    }
+  })
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn stopFollowingJournal(
+  follows: State<'_, JournalFollowState>,
+  followId: u32,
+) -> Result<ResponseModel, AppError> {
+  let controller = JournalController::new(follows.inner().clone());
+  controller.stopFollowingJournal(followId)
+}
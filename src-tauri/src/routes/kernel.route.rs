@@ -0,0 +1,19 @@
+/* controllers */
+use crate::controllers::kernel_controller::KernelController;
+
+/* models */
+use crate::models::{AppError, ResponseModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getKernels() -> Result<ResponseModel, AppError> {
+  let controller = KernelController::new();
+  controller.getKernels()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn removeKernels(versions: Vec<String>) -> Result<ResponseModel, AppError> {
+  let controller = KernelController::new();
+  controller.removeKernels(versions)
+}
@@ -0,0 +1,19 @@
+/* controllers */
+use crate::controllers::knowledge_controller::KnowledgeController;
+
+/* models */
+use crate::models::{AppError, ResponseModel, ServiceScope};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getKnowledgeBase() -> Result<ResponseModel, AppError> {
+  let controller = KnowledgeController::new();
+  controller.getKnowledgeBase()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getServiceRecommendations(scope: Option<ServiceScope>) -> Result<ResponseModel, AppError> {
+  let controller = KnowledgeController::new();
+  controller.getServiceRecommendations(scope.unwrap_or_default())
+}
@@ -0,0 +1,12 @@
+/* controllers */
+use crate::controllers::log_controller::LogController;
+
+/* models */
+use crate::models::{AppError, LogPageQueryModel, ResponseModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getLogPage(query: LogPageQueryModel) -> Result<ResponseModel, AppError> {
+  let controller = LogController::new();
+  controller.getLogPage(query)
+}
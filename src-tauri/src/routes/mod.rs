@@ -8,3 +8,39 @@ pub mod dashboard_route;
 
 #[path = "system.route.rs"]
 pub mod system_route;
+
+#[path = "schedule.route.rs"]
+pub mod schedule_route;
+
+#[path = "monitor.route.rs"]
+pub mod monitor_route;
+
+#[path = "settings.route.rs"]
+pub mod settings_route;
+
+#[path = "profile.route.rs"]
+pub mod profile_route;
+
+#[path = "journal.route.rs"]
+pub mod journal_route;
+
+#[path = "boot.route.rs"]
+pub mod boot_route;
+
+#[path = "knowledge.route.rs"]
+pub mod knowledge_route;
+
+#[path = "snapshot.route.rs"]
+pub mod snapshot_route;
+
+#[path = "elevation.route.rs"]
+pub mod elevation_route;
+
+#[path = "log.route.rs"]
+pub mod log_route;
+
+#[path = "package.route.rs"]
+pub mod package_route;
+
+#[path = "kernel.route.rs"]
+pub mod kernel_route;
@@ -0,0 +1,18 @@
+/* tauri */
+use tauri::State;
+
+/* controllers */
+use crate::controllers::monitor_controller::MonitorController;
+
+/* services */
+use crate::services::settings_service::SettingsState;
+
+/* models */
+use crate::models::{AppError, ResponseModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getDiskUsage(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = MonitorController::new(settings.inner().clone());
+  controller.getDiskUsage()
+}
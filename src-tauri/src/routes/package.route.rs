@@ -0,0 +1,33 @@
+/* controllers */
+use crate::controllers::package_controller::PackageController;
+
+/* models */
+use crate::models::{AppError, PackageCacheCleanModel, ResponseModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getPackageCache() -> Result<ResponseModel, AppError> {
+  let controller = PackageController::new();
+  controller.getPackageCache()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn cleanPackageCache(clean: Option<PackageCacheCleanModel>) -> Result<ResponseModel, AppError> {
+  let controller = PackageController::new();
+  controller.cleanPackageCache(clean.unwrap_or_default())
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getOrphanPackages() -> Result<ResponseModel, AppError> {
+  let controller = PackageController::new();
+  controller.getOrphanPackages()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn removeOrphanPackages(packages: Vec<String>) -> Result<ResponseModel, AppError> {
+  let controller = PackageController::new();
+  controller.removeOrphanPackages(packages)
+}
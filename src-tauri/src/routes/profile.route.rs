@@ -0,0 +1,49 @@
+/* tauri */
+use tauri::State;
+
+/* controllers */
+use crate::controllers::profile_controller::ProfileController;
+
+/* services */
+use crate::services::settings_service::SettingsState;
+
+/* models */
+use crate::models::{AppError, CleanupProfileModel, ResponseModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getProfiles(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = ProfileController::new(settings.inner().clone());
+  controller.getProfiles()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn saveProfile(
+  settings: State<'_, SettingsState>,
+  profile: CleanupProfileModel,
+) -> Result<ResponseModel, AppError> {
+  let controller = ProfileController::new(settings.inner().clone());
+  controller.saveProfile(profile)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn removeProfile(
+  settings: State<'_, SettingsState>,
+  id: String,
+) -> Result<ResponseModel, AppError> {
+  let controller = ProfileController::new(settings.inner().clone());
+  controller.removeProfile(&id)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn runProfile(
+  settings: State<'_, SettingsState>,
+  id: String,
+  planToken: Option<String>,
+) -> Result<ResponseModel, AppError> {
+  let controller = ProfileController::new(settings.inner().clone());
+  controller.runProfile(&id, planToken.as_deref())
+}
@@ -0,0 +1,48 @@
+/* tauri */
+use tauri::State;
+
+/* controllers */
+use crate::controllers::schedule_controller::ScheduleController;
+
+/* services */
+use crate::services::settings_service::SettingsState;
+
+/* models */
+use crate::models::{AppError, ResponseModel, ScheduleModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getSchedules(settings: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = ScheduleController::new(settings.inner().clone());
+  controller.getSchedules()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn saveSchedule(
+  settings: State<'_, SettingsState>,
+  schedule: ScheduleModel,
+) -> Result<ResponseModel, AppError> {
+  let controller = ScheduleController::new(settings.inner().clone());
+  controller.saveSchedule(schedule)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn removeSchedule(
+  settings: State<'_, SettingsState>,
+  id: String,
+) -> Result<ResponseModel, AppError> {
+  let controller = ScheduleController::new(settings.inner().clone());
+  controller.removeSchedule(&id)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn runScheduleNow(
+  settings: State<'_, SettingsState>,
+  id: String,
+) -> Result<ResponseModel, AppError> {
+  let controller = ScheduleController::new(settings.inner().clone());
+  controller.runSchedule(&id)
+}
@@ -0,0 +1,28 @@
+/* tauri */
+use tauri::State;
+
+/* controllers */
+use crate::controllers::settings_controller::SettingsController;
+
+/* services */
+use crate::services::settings_service::SettingsState;
+
+/* models */
+use crate::models::{AppError, ResponseModel, SettingsModel};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getSettings(state: State<'_, SettingsState>) -> Result<ResponseModel, AppError> {
+  let controller = SettingsController::new();
+  controller.getSettings(&state)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn updateSettings(
+  state: State<'_, SettingsState>,
+  settings: SettingsModel,
+) -> Result<ResponseModel, AppError> {
+  let controller = SettingsController::new();
+  controller.updateSettings(&state, settings)
+}
@@ -0,0 +1,43 @@
+/* controllers */
+use crate::controllers::snapshot_controller::SnapshotController;
+
+/* models */
+use crate::models::{AppError, ResponseModel, ServiceScope};
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn getServiceSnapshots() -> Result<ResponseModel, AppError> {
+  let controller = SnapshotController::new();
+  controller.getServiceSnapshots()
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn createServiceSnapshot(
+  name: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SnapshotController::new();
+  controller.createServiceSnapshot(&name, scope.unwrap_or_default())
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn removeServiceSnapshot(id: String) -> Result<ResponseModel, AppError> {
+  let controller = SnapshotController::new();
+  controller.removeServiceSnapshot(&id)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn diffServiceSnapshot(id: String) -> Result<ResponseModel, AppError> {
+  let controller = SnapshotController::new();
+  controller.diffServiceSnapshot(&id)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn restoreServiceSnapshot(id: String) -> Result<ResponseModel, AppError> {
+  let controller = SnapshotController::new();
+  controller.restoreServiceSnapshot(&id)
+}
@@ -2,53 +2,212 @@
 use crate::controllers::system_controller::SystemController;
 
 /* models */
-use crate::models::ResponseModel;
+use crate::models::{AppError, ResponseModel, ServiceScope};
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn stopService(service: &str) -> Result<ResponseModel, ResponseModel> {
+pub fn stopService(service: &str, scope: Option<ServiceScope>) -> Result<ResponseModel, AppError> {
   let controller = SystemController::new();
-  controller.stopService(service)
+  controller.stopService(scope.unwrap_or_default(), service)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn stopSelectedServices(services: Vec<String>) -> Result<ResponseModel, ResponseModel> {
+pub fn stopSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
   let controller = SystemController::new();
-  controller.stopSelectedServices(services)
+  controller.stopSelectedServices(scope.unwrap_or_default(), services)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn openFile(path: String, command: Option<String>) -> Result<ResponseModel, ResponseModel> {
+pub fn openFile(path: String, command: Option<String>) -> Result<ResponseModel, AppError> {
   let controller = SystemController::new();
   controller.openFile(&path, command)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn getAllServices() -> Result<ResponseModel, ResponseModel> {
+pub fn getAllServices(scope: Option<ServiceScope>) -> Result<ResponseModel, AppError> {
   let controller = SystemController::new();
-  controller.getAllServices()
+  controller.getAllServices(scope.unwrap_or_default())
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn enableService(service: String) -> Result<ResponseModel, ResponseModel> {
+pub fn getTimers(scope: Option<ServiceScope>) -> Result<ResponseModel, AppError> {
   let controller = SystemController::new();
-  controller.enableService(&service)
+  controller.getTimers(scope.unwrap_or_default())
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn startService(service: String) -> Result<ResponseModel, ResponseModel> {
+pub fn getServiceDetails(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
   let controller = SystemController::new();
-  controller.startService(&service)
+  controller.getServiceDetails(scope.unwrap_or_default(), &service)
 }
 
 #[tauri::command]
 #[allow(non_snake_case)]
-pub fn enableSelectedServices(services: Vec<String>) -> Result<ResponseModel, ResponseModel> {
+pub fn enableService(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
   let controller = SystemController::new();
-  controller.enableSelectedServices(services)
+  controller.enableService(scope.unwrap_or_default(), &service)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn startService(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.startService(scope.unwrap_or_default(), &service)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn enableSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.enableSelectedServices(scope.unwrap_or_default(), services)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn restartService(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.restartService(scope.unwrap_or_default(), &service)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn reloadService(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.reloadService(scope.unwrap_or_default(), &service)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn disableService(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.disableService(scope.unwrap_or_default(), &service)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn maskService(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.maskService(scope.unwrap_or_default(), &service)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn unmaskService(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.unmaskService(scope.unwrap_or_default(), &service)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn resetFailedService(
+  service: String,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.resetFailedService(scope.unwrap_or_default(), &service)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn resetFailedSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.resetFailedSelectedServices(scope.unwrap_or_default(), services)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn startSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.startSelectedServices(scope.unwrap_or_default(), services)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn restartSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.restartSelectedServices(scope.unwrap_or_default(), services)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn reloadSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.reloadSelectedServices(scope.unwrap_or_default(), services)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn disableSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.disableSelectedServices(scope.unwrap_or_default(), services)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn maskSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.maskSelectedServices(scope.unwrap_or_default(), services)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub fn unmaskSelectedServices(
+  services: Vec<String>,
+  scope: Option<ServiceScope>,
+) -> Result<ResponseModel, AppError> {
+  let controller = SystemController::new();
+  controller.unmaskSelectedServices(scope.unwrap_or_default(), services)
 }
@@ -0,0 +1,153 @@
+/* sys lib */
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+/* services */
+use crate::services::systemd_service::{take_property, SystemdService};
+
+/* models */
+use crate::models::{
+  AppError, BootAnalysisModel, BootTimesModel, DataValue, ErrorCode, ResponseModel, ResponseStatus,
+  ServiceScope, UnitTimingModel,
+};
+
+/* helpers */
+use serde_json::json;
+use zbus::zvariant::OwnedValue;
+
+const DEFAULT_TARGET: &str = "default.target";
+
+/// Monotonic activation timestamps of a unit, in microseconds since the kernel started.
+#[allow(non_snake_case)]
+struct UnitTimes {
+  name: String,
+  activating: u64,
+  activated: u64,
+  after: Vec<String>,
+  active: String,
+  unitFileState: String,
+}
+
+impl UnitTimes {
+  fn timing(&self, userspace: u64) -> UnitTimingModel {
+    UnitTimingModel {
+      name: self.name.clone(),
+      activationUs: self.activated.saturating_sub(self.activating),
+      activatedAtUs: self.activated.saturating_sub(userspace),
+      active: self.active.clone(),
+      unitFileState: self.unitFileState.clone(),
+      isService: self.name.ends_with(".service"),
+    }
+  }
+}
+
+pub struct BootService;
+
+#[allow(non_snake_case)]
+impl BootService {
+  pub fn getBootAnalysis(&self) -> Result<ResponseModel, AppError> {
+    let systemd = SystemdService::connect(ServiceScope::System)?;
+    let mut manager = systemd.getManagerProperties()?;
+    let mut timestamp =
+      |key: &str| take_property::<u64>(&mut manager, key).filter(|&usec| usec > 0);
+
+    let firmware = timestamp("FirmwareTimestampMonotonic");
+    let loader = timestamp("LoaderTimestampMonotonic");
+    let initrd = timestamp("InitRDTimestampMonotonic");
+    let userspace = timestamp("UserspaceTimestampMonotonic").unwrap_or_default();
+    let Some(finish) = timestamp("FinishTimestampMonotonic") else {
+      return Err(AppError::new(
+        ErrorCode::Busy,
+        "Boot has not finished yet, try again once all startup jobs are done",
+      ));
+    };
+
+    // Firmware and loader timestamps count backwards from the moment the kernel started
+    let times = BootTimesModel {
+      firmwareUs: firmware.map(|f| f.saturating_sub(loader.unwrap_or_default())),
+      loaderUs: loader,
+      kernelUs: initrd.unwrap_or(userspace),
+      initrdUs: initrd.map(|i| userspace.saturating_sub(i)),
+      userspaceUs: finish.saturating_sub(userspace),
+      totalUs: firmware.unwrap_or_default() + finish,
+    };
+
+    let units: HashMap<String, UnitTimes> = systemd
+      .listUnitProperties()?
+      .into_iter()
+      .map(unit_times)
+      .map(|unit| (unit.name.clone(), unit))
+      .collect();
+
+    let mut blame: Vec<UnitTimingModel> = units
+      .values()
+      .filter(|unit| unit.activating > 0 && unit.activated > unit.activating)
+      .map(|unit| unit.timing(userspace))
+      .collect();
+    blame.sort_by_key(|timing| Reverse(timing.activationUs));
+
+    // default.target is an alias, so the chain starts from the unit it resolves to
+    let defaultTarget = systemd.getUnit(DEFAULT_TARGET)?.name;
+    let analysis = BootAnalysisModel {
+      times,
+      blame,
+      criticalChain: critical_chain(&units, &defaultTarget, userspace),
+    };
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "Boot took {:.1}s",
+        analysis.times.totalUs as f64 / 1_000_000.0
+      ),
+      data: DataValue::Object(serde_json::to_value(analysis).unwrap_or(json!({}))),
+    })
+  }
+}
+
+fn unit_times(mut properties: HashMap<String, OwnedValue>) -> UnitTimes {
+  UnitTimes {
+    name: take_property(&mut properties, "Id").unwrap_or_default(),
+    activating: take_property(&mut properties, "InactiveExitTimestampMonotonic")
+      .unwrap_or_default(),
+    activated: take_property(&mut properties, "ActiveEnterTimestampMonotonic").unwrap_or_default(),
+    after: take_property(&mut properties, "After").unwrap_or_default(),
+    active: take_property(&mut properties, "ActiveState").unwrap_or_default(),
+    unitFileState: take_property(&mut properties, "UnitFileState").unwrap_or_default(),
+  }
+}
+
+/// Follows the chain of units that delayed the default target, as `systemd-analyze
+/// critical-chain` does: from each unit, step to the `After=` dependency that became
+/// active last before the unit itself started.
+#[allow(non_snake_case)]
+fn critical_chain(
+  units: &HashMap<String, UnitTimes>,
+  target: &str,
+  userspace: u64,
+) -> Vec<UnitTimingModel> {
+  let mut chain = Vec::new();
+  let mut seen = HashSet::new();
+  let mut current = units.get(target);
+
+  while let Some(unit) = current {
+    if !seen.insert(unit.name.as_str()) {
+      break;
+    }
+    chain.push(unit.timing(userspace));
+
+    let startedAt = if unit.activating > 0 {
+      unit.activating
+    } else {
+      unit.activated
+    };
+    current = unit
+      .after
+      .iter()
+      .filter_map(|name| units.get(name))
+      .filter(|dependency| dependency.activated > 0 && dependency.activated <= startedAt)
+      .max_by_key(|dependency| dependency.activated);
+  }
+
+  chain
+}
@@ -1,32 +1,88 @@
 /* sys lib */
+use std::ffi::OsStr;
 use std::fs;
-use std::path::Path;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/* services */
+use crate::services::helper_service::HelperService;
+use crate::services::journal_service::journal_usage;
+use crate::services::log_service::{analyze_logs, group_log_families, log_file_kind};
 
 /* models */
 use crate::models::{
-  CacheFileModel, DataValue, LargeFileModel, LogFileModel, ResponseModel, ResponseStatus,
-  TrashFileModel,
+  AppError, BatchResultModel, CacheFileModel, CleanupCategory, CleanupPreviewModel,
+  CleanupResultModel, CleanupRuleModel, DataValue, ErrorCode, ItemResultModel, JournalVacuumModel,
+  JournalVacuumResultModel, LargeFileModel, LogFileKind, LogFileModel, LogRetentionModel,
+  LogRetentionResultModel, ResponseModel, ResponseStatus, SettingsModel, TrashFileModel,
 };
 
 /* helpers */
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
 use rayon::prelude::*;
 use serde_json::json;
 use walkdir::WalkDir;
 
-pub struct CleanerService;
+pub struct CleanerService {
+  settings: SettingsModel,
+}
+
+pub struct CleanupPlan {
+  category: CleanupCategory,
+  candidates: Vec<CleanupCandidate>,
+}
+
+struct CleanupCandidate {
+  paths: Vec<PathBuf>,
+  size: u64,
+}
+
+impl CleanupPlan {
+  pub fn preview(&self) -> CleanupPreviewModel {
+    CleanupPreviewModel {
+      category: self.category,
+      fileCount: self.candidates.len(),
+      totalSize: self.candidates.iter().map(|c| c.size).sum(),
+    }
+  }
+}
+
+/// Token identifying exactly which paths a set of plans would remove, so a previewed plan
+/// can be confirmed and a changed one refused.
+pub fn plan_token(plans: &[CleanupPlan]) -> String {
+  let mut hasher = DefaultHasher::new();
+  for plan in plans {
+    plan.category.hash(&mut hasher);
+    for candidate in &plan.candidates {
+      candidate.paths.hash(&mut hasher);
+      candidate.size.hash(&mut hasher);
+    }
+  }
+  format!("{:016x}", hasher.finish())
+}
 
 #[allow(non_snake_case)]
 impl CleanerService {
-  pub fn getCacheFiles(&self) -> Result<ResponseModel, ResponseModel> {
-    let cacheDir = dirs::cache_dir().ok_or("Cache directory not found")?;
+  pub fn new(settings: SettingsModel) -> Self {
+    Self { settings }
+  }
+
+  pub fn getCacheFiles(&self) -> Result<ResponseModel, AppError> {
+    let cacheDir = self
+      .settings
+      .cache
+      .resolveCacheDir()
+      .ok_or_else(|| AppError::notFound("Cache directory not found"))?;
 
     let files: Vec<CacheFileModel> = WalkDir::new(cacheDir)
-      .max_depth(4)
+      .max_depth(self.settings.cache.maxDepth)
       .into_iter()
       .filter_map(|e| e.ok())
       .filter(|e| e.file_type().is_file())
-      .take(1000)
+      .take(self.settings.cache.listLimit)
       .collect::<Vec<_>>()
       .into_par_iter()
       .filter_map(|entry| {
@@ -56,8 +112,8 @@ impl CleanerService {
     })
   }
 
-  pub fn getTrashFiles(&self) -> Result<ResponseModel, ResponseModel> {
-    let home = dirs::home_dir().ok_or("Home directory not found")?;
+  pub fn getTrashFiles(&self) -> Result<ResponseModel, AppError> {
+    let home = dirs::home_dir().ok_or_else(|| AppError::notFound("Home directory not found"))?;
     let trashDir = home.join(".local/share/Trash/files");
 
     let mut trashFiles = Vec::new();
@@ -96,31 +152,8 @@ impl CleanerService {
     })
   }
 
-  pub fn getSystemLogs(&self) -> Result<ResponseModel, ResponseModel> {
-    let logDir = Path::new("/var/log");
-
-    let files: Vec<LogFileModel> = WalkDir::new(logDir)
-      .max_depth(3)
-      .into_iter()
-      .filter_map(|e| e.ok())
-      .filter(|e| e.file_type().is_file())
-      .take(500)
-      .collect::<Vec<_>>()
-      .into_par_iter()
-      .filter_map(|entry| {
-        let path = entry.path();
-        let metadata = fs::metadata(path).ok()?;
-        let modified: DateTime<Local> = metadata
-          .modified()
-          .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
-          .into();
-        Some(LogFileModel {
-          path: path.to_string_lossy().to_string(),
-          size: metadata.len(),
-          modified: modified.format("%Y-%m-%d %H:%M:%S").to_string(),
-        })
-      })
-      .collect();
+  pub fn getSystemLogs(&self) -> Result<ResponseModel, AppError> {
+    let files = self.listLogFiles();
 
     Ok(ResponseModel {
       status: ResponseStatus::Success,
@@ -134,17 +167,123 @@ impl CleanerService {
     })
   }
 
-  pub fn getLargeFiles(&self) -> Result<ResponseModel, ResponseModel> {
-    let home = dirs::home_dir().ok_or("Home directory not found")?;
-    let threshold = 100 * 1024 * 1024;
+  /// Analyzes the contents of the active and rotated logs `getSystemLogs` lists.
+  pub fn analyzeSystemLogs(&self) -> Result<ResponseModel, AppError> {
+    let files = self.listLogFiles();
+    let analysis = analyze_logs(&files, self.settings.logs.analysisMaxBytes);
 
-    let dirsToScan = vec![
-      home.join("Downloads"),
-      home.join("Documents"),
-      home.join("Videos"),
-      home.join("Pictures"),
-      home.join("Desktop"),
-    ];
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "Analyzed {} of {} logs: {} errors and {} warnings",
+        analysis.analyzedFiles,
+        files.len(),
+        analysis.severities.critical + analysis.severities.error,
+        analysis.severities.warning
+      ),
+      data: DataValue::Object(serde_json::to_value(analysis).unwrap_or(json!({}))),
+    })
+  }
+
+  /// Active and rotated logs with their rotated copies grouped by the log they came from.
+  pub fn getLogFamilies(&self) -> Result<ResponseModel, AppError> {
+    let families = group_log_families(self.listLogFiles());
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Found {} log families", families.len()),
+      data: DataValue::Array(
+        families
+          .into_iter()
+          .map(|f| serde_json::to_value(f).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
+  }
+
+  /// Deletes rotated copies beyond the newest `keepRotations` of each family or older
+  /// than `maxAgeDays`, on top of whatever logrotate keeps.
+  pub fn applyLogRetention(&self, retention: LogRetentionModel) -> Result<ResponseModel, AppError> {
+    if retention.keepRotations.is_none() && retention.maxAgeDays.is_none() {
+      return Err(AppError::invalidInput(
+        "A rotation count or age limit is required",
+      ));
+    }
+    let cutoff = retention
+      .maxAgeDays
+      .map(|days| SystemTime::now() - Duration::from_secs(u64::from(days) * 24 * 60 * 60));
+
+    let candidates: Vec<LogFileModel> = group_log_families(self.listLogFiles())
+      .into_iter()
+      .flat_map(|family| {
+        family
+          .rotations
+          .into_iter()
+          .enumerate()
+          .filter(|(index, rotation)| {
+            let beyondCount = retention.keepRotations.is_some_and(|keep| *index >= keep);
+            let tooOld = cutoff.is_some()
+              && fs::metadata(&rotation.path)
+                .is_ok_and(|metadata| is_older_than(&metadata, cutoff));
+            beyondCount || tooOld
+          })
+          .map(|(_, rotation)| rotation)
+      })
+      .collect();
+    let candidateSize: u64 = candidates.iter().map(|c| c.size).sum();
+
+    if retention.dryRun || candidates.is_empty() {
+      let message = format!(
+        "{} rotated logs ({} bytes) are beyond the retention policy",
+        candidates.len(),
+        candidateSize
+      );
+      let result = LogRetentionResultModel {
+        batch: BatchResultModel::default(),
+        candidates,
+        freedBytes: 0,
+      };
+      return Ok(ResponseModel {
+        status: ResponseStatus::Success,
+        message,
+        data: DataValue::Object(serde_json::to_value(result).unwrap_or(json!({}))),
+      });
+    }
+
+    let logDir = PathBuf::from(&self.settings.logs.logDir);
+    let paths = candidates.iter().map(|c| c.path.clone()).collect();
+    let batch = clean_log_files(paths, &logDir);
+    let freedBytes = candidates
+      .iter()
+      .filter(|c| !Path::new(&c.path).exists())
+      .map(|c| c.size)
+      .sum();
+    let error = batch.toError("Removed");
+    let succeeded = batch.succeeded;
+    let result = LogRetentionResultModel {
+      batch,
+      candidates,
+      freedBytes,
+    };
+    let data = DataValue::Object(serde_json::to_value(result).unwrap_or(json!({})));
+    if let Some(error) = error {
+      return Err(error.withData(data));
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "Removed {} rotated logs, freeing {} bytes",
+        succeeded, freedBytes
+      ),
+      data,
+    })
+  }
+
+  pub fn getLargeFiles(&self) -> Result<ResponseModel, AppError> {
+    let largeFiles = &self.settings.largeFiles;
+    let threshold = largeFiles.thresholdBytes;
+    let dirsToScan = largeFiles.resolveRoots();
 
     let mut files: Vec<LargeFileModel> = dirsToScan
       .into_par_iter()
@@ -153,7 +292,7 @@ impl CleanerService {
           return Vec::new();
         }
         WalkDir::new(dir)
-          .max_depth(3)
+          .max_depth(largeFiles.maxDepth)
           .into_iter()
           .filter_map(|e| e.ok())
           .filter(|e| e.file_type().is_file())
@@ -174,15 +313,15 @@ impl CleanerService {
               None
             }
           })
-          .take(50)
+          .take(largeFiles.perRootLimit)
           .collect::<Vec<_>>()
       })
       .flatten()
       .collect();
 
     files.sort_by(|a, b| b.size.cmp(&a.size));
-    if files.len() > 200 {
-      files.truncate(200);
+    if files.len() > largeFiles.listLimit {
+      files.truncate(largeFiles.listLimit);
     }
 
     Ok(ResponseModel {
@@ -197,75 +336,41 @@ impl CleanerService {
     })
   }
 
-  pub fn clearSelectedCacheFiles(
-    &self,
-    paths: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
-    let mut cleared = 0;
-    let mut errors = Vec::new();
+  pub fn clearSelectedCacheFiles(&self, paths: Vec<String>) -> Result<ResponseModel, AppError> {
+    let cacheDir = self
+      .settings
+      .cache
+      .resolveCacheDir()
+      .ok_or_else(|| AppError::notFound("Cache directory not found"))?;
 
-    for path in paths {
-      if let Err(e) = fs::remove_file(&path) {
-        errors.push(format!("{}: {}", path, e));
-      } else {
-        cleared += 1;
-      }
+    let batch = remove_selected_files(paths, &[cacheDir]);
+    if let Some(error) = batch.toError("Cleared") {
+      return Err(error);
     }
 
-    if errors.is_empty() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Successfully cleared {} cache files", cleared),
-        data: DataValue::String("".to_string()),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!(
-          "Cleared {} files, failed on: {}",
-          cleared,
-          errors.join("; ")
-        ),
-        data: DataValue::String("".to_string()),
-      })
-    }
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Successfully cleared {} cache files", batch.succeeded),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
   }
 
-  pub fn clearSelectedTrashFiles(
-    &self,
-    paths: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
-    let mut cleared = 0;
-    let mut errors = Vec::new();
+  pub fn clearSelectedTrashFiles(&self, paths: Vec<String>) -> Result<ResponseModel, AppError> {
+    let home = dirs::home_dir().ok_or_else(|| AppError::notFound("Home directory not found"))?;
 
-    for path in paths {
-      if let Err(e) = fs::remove_file(&path) {
-        errors.push(format!("{}: {}", path, e));
-      } else {
-        cleared += 1;
-      }
+    let batch = remove_selected_files(paths, &[home.join(".local/share/Trash")]);
+    if let Some(error) = batch.toError("Cleared") {
+      return Err(error);
     }
 
-    if errors.is_empty() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Successfully cleared {} trash files", cleared),
-        data: DataValue::String("".to_string()),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!(
-          "Cleared {} files, failed on: {}",
-          cleared,
-          errors.join("; ")
-        ),
-        data: DataValue::String("".to_string()),
-      })
-    }
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Successfully cleared {} trash files", batch.succeeded),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
   }
 
-  pub fn clearSelectedLogFiles(&self, paths: Vec<String>) -> Result<ResponseModel, ResponseModel> {
+  pub fn clearSelectedLogFiles(&self, paths: Vec<String>) -> Result<ResponseModel, AppError> {
     if paths.is_empty() {
       return Ok(ResponseModel {
         status: ResponseStatus::Success,
@@ -274,157 +379,129 @@ impl CleanerService {
       });
     }
 
-    // Batch all paths into a single pkexec command
-    let mut cmd = std::process::Command::new("pkexec");
-    cmd.arg("rm").arg("-f");
-    for path in &paths {
-      cmd.arg(path);
+    let logDir = PathBuf::from(&self.settings.logs.logDir);
+    let batch = clean_log_files(paths, &logDir);
+    if let Some(error) = batch.toError("Cleared") {
+      return Err(error);
     }
 
-    let output = cmd.output().map_err(|e| ResponseModel {
-      status: ResponseStatus::Error,
-      message: format!("Failed to run pkexec: {}", e),
-      data: DataValue::String("".to_string()),
-    })?;
-
-    if output.status.success() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Successfully cleared {} log files", paths.len()),
-        data: DataValue::String("".to_string()),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!(
-          "Failed to clear log files: {}",
-          String::from_utf8_lossy(&output.stderr).trim()
-        ),
-        data: DataValue::String("".to_string()),
-      })
-    }
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Successfully cleared {} log files", batch.succeeded),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
   }
 
-  pub fn clearSelectedLargeFiles(
-    &self,
-    paths: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
-    let mut cleared = 0;
-    let mut errors = Vec::new();
-
-    for path in paths {
-      if let Err(e) = fs::remove_file(&path) {
-        errors.push(format!("{}: {}", path, e));
-      } else {
-        cleared += 1;
-      }
+  pub fn clearSelectedLargeFiles(&self, paths: Vec<String>) -> Result<ResponseModel, AppError> {
+    let batch = remove_selected_files(paths, &self.settings.largeFiles.resolveRoots());
+    if let Some(error) = batch.toError("Cleared") {
+      return Err(error);
     }
 
-    if errors.is_empty() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Successfully cleared {} large files", cleared),
-        data: DataValue::String("".to_string()),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!(
-          "Cleared {} files, failed on: {}",
-          cleared,
-          errors.join("; ")
-        ),
-        data: DataValue::String("".to_string()),
-      })
-    }
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Successfully cleared {} large files", batch.succeeded),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
   }
 
-  pub fn clearTrash(&self) -> Result<ResponseModel, ResponseModel> {
-    let home = dirs::home_dir().ok_or("Home directory not found")?;
+  pub fn clearTrash(&self) -> Result<ResponseModel, AppError> {
+    let home = dirs::home_dir().ok_or_else(|| AppError::notFound("Home directory not found"))?;
     let trashDir = home.join(".local/share/Trash/files");
-    match fs::read_dir(&trashDir) {
-      Ok(entries) => {
-        for entry in entries.flatten() {
-          let path = entry.path();
-          if path.is_file() {
-            if let Err(e) = fs::remove_file(&path) {
-              return Err(ResponseModel {
-                status: ResponseStatus::Error,
-                message: format!("Failed to remove {}: {}", path.display(), e),
-                data: DataValue::String("".to_string()),
-              });
-            }
-          }
-        }
-        Ok(ResponseModel {
-          status: ResponseStatus::Success,
-          message: "Trash cleared successfully".to_string(),
-          data: DataValue::String("".to_string()),
-        })
+    let entries = fs::read_dir(&trashDir).map_err(|e| AppError::io("Failed to read trash", &e))?;
+
+    let mut batch = BatchResultModel::default();
+    for entry in entries.flatten() {
+      let path = entry.path();
+      if path.is_file() {
+        let result =
+          fs::remove_file(&path).map_err(|e| AppError::io(format!("{}", path.display()), &e));
+        batch.record(path.to_string_lossy(), result);
       }
-      Err(e) => Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!("Failed to read trash: {}", e),
-        data: DataValue::String("".to_string()),
-      }),
     }
+    if let Some(error) = batch.toError("Removed") {
+      return Err(error);
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: "Trash cleared successfully".to_string(),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
   }
 
-  pub fn clearCache(&self) -> Result<ResponseModel, ResponseModel> {
-    let cacheDir = dirs::cache_dir().ok_or("Cache directory not found")?;
-    if cacheDir.exists() {
-      match fs::remove_dir_all(&cacheDir) {
-        Ok(_) => {
-          // Re-create the empty directory
-          let _ = fs::create_dir_all(&cacheDir);
-          Ok(ResponseModel {
-            status: ResponseStatus::Success,
-            message: "Cache directory cleared successfully".to_string(),
-            data: DataValue::String("".to_string()),
-          })
-        }
-        Err(e) => Err(ResponseModel {
-          status: ResponseStatus::Error,
-          message: format!("Failed to clear cache directory: {}", e),
-          data: DataValue::String("".to_string()),
-        }),
-      }
-    } else {
-      Ok(ResponseModel {
+  pub fn clearCache(&self) -> Result<ResponseModel, AppError> {
+    let cacheDir = self
+      .settings
+      .cache
+      .resolveCacheDir()
+      .ok_or_else(|| AppError::notFound("Cache directory not found"))?;
+    if !cacheDir.exists() {
+      return Ok(ResponseModel {
         status: ResponseStatus::Info,
         message: "No cache to clear".to_string(),
         data: DataValue::String("".to_string()),
-      })
+      });
+    }
+
+    // Settings are validated, but $XDG_CACHE_HOME or a symlink can still point elsewhere
+    let cacheDir = cacheDir
+      .canonicalize()
+      .map_err(|e| AppError::io(cacheDir.display(), &e))?;
+    let home = dirs::home_dir().and_then(|home| home.canonicalize().ok());
+    if !home.is_some_and(|home| cacheDir.starts_with(&home) && cacheDir != home) {
+      return Err(AppError::new(
+        ErrorCode::OutsideAllowedRoot,
+        format!(
+          "Refusing to clear {}, which is not inside the home directory",
+          cacheDir.display()
+        ),
+      ));
+    }
+
+    // Only the contents go, so the directory keeps its permissions and any mount on it
+    let entries =
+      fs::read_dir(&cacheDir).map_err(|e| AppError::io("Failed to read cache directory", &e))?;
+    let mut batch = BatchResultModel::default();
+    for entry in entries.filter_map(|e| e.ok()) {
+      let path = entry.path();
+      let result = remove_path(&path).map_err(|e| AppError::io(path.display(), &e));
+      batch.record(path.to_string_lossy(), result);
     }
+    if let Some(error) = batch.toError("Cleared") {
+      return Err(error.withData(DataValue::Object(
+        serde_json::to_value(&batch).unwrap_or(json!({})),
+      )));
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: "Cache directory cleared successfully".to_string(),
+      data: DataValue::String("".to_string()),
+    })
   }
 
-  pub fn clearAllLogs(&self) -> Result<ResponseModel, ResponseModel> {
-    let logDir = Path::new("/var/log");
+  pub fn clearAllLogs(&self) -> Result<ResponseModel, AppError> {
+    let logDir = Path::new(&self.settings.logs.logDir);
 
-    let files: Vec<LogFileModel> = WalkDir::new(logDir)
-      .max_depth(3)
+    let paths: Vec<String> = WalkDir::new(logDir)
+      .max_depth(self.settings.logs.maxDepth)
       .into_iter()
       .filter_map(|e| e.ok())
       .filter(|e| e.file_type().is_file())
-      .take(500)
-      .collect::<Vec<_>>()
-      .into_par_iter()
-      .filter_map(|entry| {
-        let path = entry.path();
-        let metadata = fs::metadata(path).ok()?;
-        let modified: DateTime<Local> = metadata
-          .modified()
-          .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
-          .into();
-        Some(LogFileModel {
-          path: path.to_string_lossy().to_string(),
-          size: metadata.len(),
-          modified: modified.format("%Y-%m-%d %H:%M:%S").to_string(),
-        })
+      .filter(|e| {
+        matches!(
+          log_file_kind(e.path()),
+          LogFileKind::Active | LogFileKind::Rotated
+        )
       })
+      // Truncating an empty log frees nothing
+      .filter(|e| e.metadata().is_ok_and(|m| m.len() > 0))
+      .take(self.settings.logs.listLimit)
+      .map(|entry| entry.path().to_string_lossy().to_string())
       .collect();
 
-    if files.is_empty() {
+    if paths.is_empty() {
       return Ok(ResponseModel {
         status: ResponseStatus::Success,
         message: "No log files found to clear".to_string(),
@@ -432,105 +509,146 @@ impl CleanerService {
       });
     }
 
-    // Batch all paths into a single pkexec command
-    let mut cmd = std::process::Command::new("pkexec");
-    cmd.arg("rm").arg("-f");
-    for log in &files {
-      cmd.arg(&log.path);
+    let batch = clean_log_files(paths, logDir);
+    if let Some(error) = batch.toError("Cleared") {
+      return Err(error);
     }
 
-    let output = cmd.output().map_err(|e| ResponseModel {
-      status: ResponseStatus::Error,
-      message: format!("Failed to run pkexec: {}", e),
-      data: DataValue::String("0".to_string()),
-    })?;
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Cleared {} log files", batch.succeeded),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
+  }
 
-    if output.status.success() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Cleared {} log files", files.len()),
-        data: DataValue::String(files.len().to_string()),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!(
-          "Failed to clear logs: {}",
-          String::from_utf8_lossy(&output.stderr).trim()
-        ),
-        data: DataValue::String("0".to_string()),
-      })
-    }
+  pub fn getJournalUsage(&self) -> Result<ResponseModel, AppError> {
+    let usage = journal_usage();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "The journal takes {} bytes in {} files",
+        usage.totalSize, usage.fileCount
+      ),
+      data: DataValue::Object(serde_json::to_value(usage).unwrap_or(json!({}))),
+    })
   }
 
-  pub fn clearAllLargeFiles(&self) -> Result<ResponseModel, ResponseModel> {
-    let home = dirs::home_dir().ok_or("Home directory not found")?;
-    let threshold = 100 * 1024 * 1024;
+  /// Vacuums the journal through journald's own mechanism, which removes whole archived
+  /// files and keeps the journal consistent.
+  pub fn vacuumJournal(&self, vacuum: JournalVacuumModel) -> Result<ResponseModel, AppError> {
+    let before = journal_usage();
+    let output = HelperService::connect()?.vacuumJournal(&vacuum)?;
+    let after = journal_usage();
+    let result = JournalVacuumResultModel {
+      freedBytes: before.totalSize.saturating_sub(after.totalSize),
+      before,
+      after,
+      output,
+    };
 
-    let dirsToScan = vec![
-      home.join("Downloads"),
-      home.join("Documents"),
-      home.join("Videos"),
-      home.join("Pictures"),
-      home.join("Desktop"),
-    ];
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Freed {} bytes from the journal", result.freedBytes),
+      data: DataValue::Object(serde_json::to_value(result).unwrap_or(json!({}))),
+    })
+  }
 
-    let files: Vec<LargeFileModel> = dirsToScan
-      .into_par_iter()
+  pub fn clearAllLargeFiles(&self) -> Result<ResponseModel, AppError> {
+    let largeFiles = &self.settings.largeFiles;
+    let threshold = largeFiles.thresholdBytes;
+    let dirsToScan = largeFiles.resolveRoots();
+
+    let paths: Vec<String> = dirsToScan
+      .par_iter()
       .flat_map(|dir| {
         if !dir.exists() {
           return Vec::new();
         }
         WalkDir::new(dir)
-          .max_depth(3)
+          .max_depth(largeFiles.maxDepth)
           .into_iter()
           .filter_map(|e| e.ok())
           .filter(|e| e.file_type().is_file())
-          .filter_map(|entry| {
-            let metadata = entry.metadata().ok()?;
-            if metadata.len() > threshold {
-              let modified: DateTime<Local> = metadata
-                .modified()
-                .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
-                .into();
-              Some(LargeFileModel {
-                name: entry.file_name().to_string_lossy().to_string(),
-                path: entry.path().to_string_lossy().to_string(),
-                size: metadata.len(),
-                modified: modified.format("%Y-%m-%d %H:%M:%S").to_string(),
-              })
-            } else {
-              None
-            }
+          .filter(|entry| {
+            entry
+              .metadata()
+              .map(|metadata| metadata.len() > threshold)
+              .unwrap_or(false)
           })
-          .take(50)
+          .take(largeFiles.perRootLimit)
+          .map(|entry| entry.path().to_string_lossy().to_string())
           .collect::<Vec<_>>()
       })
       .collect();
 
-    let mut clearedCount = 0;
-    for file in files {
-      if fs::remove_file(file.path).is_ok() {
-        clearedCount += 1;
-      }
+    let batch = remove_selected_files(paths, &dirsToScan);
+    if let Some(error) = batch.toError("Cleared") {
+      return Err(error);
     }
 
     Ok(ResponseModel {
       status: ResponseStatus::Success,
-      message: format!("Cleared {} large files", clearedCount),
-      data: DataValue::String(clearedCount.to_string()),
+      message: format!("Cleared {} large files", batch.succeeded),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn planCleanupRule(&self, rule: &CleanupRuleModel) -> Result<CleanupPlan, AppError> {
+    let cacheDir = self
+      .settings
+      .cache
+      .resolveCacheDir()
+      .ok_or_else(|| AppError::notFound("Cache directory not found"))?;
+    let mut candidates = collect_cleanup_candidates(rule, &cacheDir)?;
+    if let Some(minSize) = rule.largerThanBytes {
+      candidates.retain(|c| c.size >= minSize);
+    }
+
+    Ok(CleanupPlan {
+      category: rule.category,
+      candidates,
     })
   }
 
-  pub fn previewFile(&self, path: String) -> Result<ResponseModel, ResponseModel> {
+  pub fn executeCleanupPlan(&self, plan: CleanupPlan) -> CleanupResultModel {
+    let mut result = CleanupResultModel {
+      category: plan.category,
+      removedFiles: 0,
+      freedBytes: 0,
+      errors: Vec::new(),
+    };
+
+    for candidate in plan.candidates {
+      let mut removed = true;
+      for path in &candidate.paths {
+        match remove_path(path) {
+          Ok(_) => {}
+          Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+          Err(e) => {
+            result.errors.push(ItemResultModel::failed(
+              path.to_string_lossy(),
+              AppError::io(path.display(), &e),
+            ));
+            removed = false;
+            break;
+          }
+        }
+      }
+      if removed {
+        result.removedFiles += 1;
+        result.freedBytes += candidate.size;
+      }
+    }
+
+    result
+  }
+
+  pub fn previewFile(&self, path: String) -> Result<ResponseModel, AppError> {
     let filePath = Path::new(&path);
 
     if !filePath.exists() {
-      return Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: "File not found".to_string(),
-        data: DataValue::String("".to_string()),
-      });
+      return Err(AppError::notFound(format!("File not found: {}", path)));
     }
 
     let extension = filePath
@@ -637,11 +755,7 @@ impl CleanerService {
 
     let responseData = match fileType {
       "image" => {
-        let bytes = fs::read(&path).map_err(|e| ResponseModel {
-          status: ResponseStatus::Error,
-          message: format!("Failed to read file: {}", e),
-          data: DataValue::String("".to_string()),
-        })?;
+        let bytes = fs::read(&path).map_err(|e| AppError::io("Failed to read file", &e))?;
         let base64 = base64_encode(&bytes);
         let mimeType = match extension.as_str() {
           "png" => "image/png",
@@ -660,11 +774,7 @@ impl CleanerService {
         })
       }
       "text" => {
-        let bytes = fs::read(&path).map_err(|e| ResponseModel {
-          status: ResponseStatus::Error,
-          message: format!("Failed to read file: {}", e),
-          data: DataValue::String("".to_string()),
-        })?;
+        let bytes = fs::read(&path).map_err(|e| AppError::io("Failed to read file", &e))?;
 
         let content = String::from_utf8_lossy(&bytes).into_owned();
         let truncatedContent = if content.len() > 50000 {
@@ -695,6 +805,36 @@ impl CleanerService {
       data: DataValue::Object(responseData),
     })
   }
+
+  /// Log files under the log directory, except journal files: they are binary and only
+  /// journald may shrink them; see getJournalUsage.
+  fn listLogFiles(&self) -> Vec<LogFileModel> {
+    let logDir = Path::new(&self.settings.logs.logDir);
+
+    WalkDir::new(logDir)
+      .max_depth(self.settings.logs.maxDepth)
+      .into_iter()
+      .filter_map(|e| e.ok())
+      .filter(|e| e.file_type().is_file() && log_file_kind(e.path()) != LogFileKind::Journal)
+      .take(self.settings.logs.listLimit)
+      .collect::<Vec<_>>()
+      .into_par_iter()
+      .filter_map(|entry| {
+        let path = entry.path();
+        let metadata = fs::metadata(path).ok()?;
+        let modified: DateTime<Local> = metadata
+          .modified()
+          .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
+          .into();
+        Some(LogFileModel {
+          path: path.to_string_lossy().to_string(),
+          size: metadata.len(),
+          modified: modified.format("%Y-%m-%d %H:%M:%S").to_string(),
+          kind: log_file_kind(path),
+        })
+      })
+      .collect()
+  }
 }
 
 fn base64_encode(data: &[u8]) -> String {
@@ -724,3 +864,299 @@ fn base64_encode(data: &[u8]) -> String {
 
   result
 }
+
+fn collect_cleanup_candidates(
+  rule: &CleanupRuleModel,
+  cache_dir: &Path,
+) -> Result<Vec<CleanupCandidate>, AppError> {
+  let cutoff = rule
+    .olderThanDays
+    .map(|days| SystemTime::now() - Duration::from_secs(u64::from(days) * 24 * 60 * 60));
+
+  let home = dirs::home_dir().ok_or_else(|| AppError::notFound("Home directory not found"))?;
+  let excluded = &rule.excludedApps;
+
+  match rule.category {
+    CleanupCategory::Cache => Ok(collect_old_files(cache_dir, cutoff, excluded)),
+    CleanupCategory::PipCache => Ok(collect_old_files(&cache_dir.join("pip"), cutoff, excluded)),
+    CleanupCategory::NpmCache => {
+      let npm_cache = std::env::var_os("npm_config_cache")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".npm"));
+      Ok(collect_old_files(
+        &npm_cache.join("_cacache"),
+        cutoff,
+        excluded,
+      ))
+    }
+    CleanupCategory::CargoCache => {
+      let cargo_home = std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".cargo"));
+      Ok(collect_old_files(
+        &cargo_home.join("registry/cache"),
+        cutoff,
+        excluded,
+      ))
+    }
+    CleanupCategory::Trash => Ok(collect_trash_entries(
+      &home.join(".local/share/Trash"),
+      cutoff,
+    )),
+    CleanupCategory::ThumbnailOrphans => Ok(collect_thumbnail_orphans(
+      &cache_dir.join("thumbnails"),
+      cutoff,
+    )),
+  }
+}
+
+fn collect_old_files(
+  dir: &Path,
+  cutoff: Option<SystemTime>,
+  excluded_apps: &[String],
+) -> Vec<CleanupCandidate> {
+  WalkDir::new(dir)
+    .into_iter()
+    .filter_entry(|e| {
+      // Top-level entries of a cache directory are owned by one application each
+      e.depth() != 1
+        || !excluded_apps
+          .iter()
+          .any(|app| e.file_name() == OsStr::new(app))
+    })
+    .filter_map(|e| e.ok())
+    .filter(|e| e.file_type().is_file())
+    .filter_map(|entry| {
+      let metadata = entry.metadata().ok()?;
+      if !is_older_than(&metadata, cutoff) {
+        return None;
+      }
+      Some(CleanupCandidate {
+        paths: vec![entry.path().to_path_buf()],
+        size: metadata.len(),
+      })
+    })
+    .collect()
+}
+
+fn collect_trash_entries(trash_dir: &Path, cutoff: Option<SystemTime>) -> Vec<CleanupCandidate> {
+  let Ok(entries) = fs::read_dir(trash_dir.join("info")) else {
+    return Vec::new();
+  };
+
+  entries
+    .flatten()
+    .filter_map(|entry| {
+      let info_path = entry.path();
+      if info_path.extension() != Some(OsStr::new("trashinfo")) {
+        return None;
+      }
+      let file_path = trash_dir.join("files").join(info_path.file_stem()?);
+      if let Some(cutoff) = cutoff {
+        let deleted = trash_deletion_date(&info_path)
+          .or_else(|| fs::symlink_metadata(&file_path).ok()?.modified().ok())?;
+        if deleted > cutoff {
+          return None;
+        }
+      }
+      Some(CleanupCandidate {
+        size: path_size(&file_path),
+        paths: vec![file_path, info_path],
+      })
+    })
+    .collect()
+}
+
+fn collect_thumbnail_orphans(
+  thumbnails_dir: &Path,
+  cutoff: Option<SystemTime>,
+) -> Vec<CleanupCandidate> {
+  WalkDir::new(thumbnails_dir)
+    .max_depth(3)
+    .into_iter()
+    .filter_map(|e| e.ok())
+    .filter(|e| e.file_type().is_file())
+    .filter(|e| e.path().extension() == Some(OsStr::new("png")))
+    .collect::<Vec<_>>()
+    .into_par_iter()
+    .filter_map(|entry| {
+      let metadata = entry.metadata().ok()?;
+      if !is_older_than(&metadata, cutoff) {
+        return None;
+      }
+      let uri = read_png_text(entry.path(), "Thumb::URI")?;
+      let source = decode_file_uri(&uri)?;
+      if source.exists() {
+        return None;
+      }
+      Some(CleanupCandidate {
+        paths: vec![entry.path().to_path_buf()],
+        size: metadata.len(),
+      })
+    })
+    .collect()
+}
+
+fn is_older_than(metadata: &fs::Metadata, cutoff: Option<SystemTime>) -> bool {
+  match cutoff {
+    Some(cutoff) => metadata
+      .modified()
+      .map(|modified| modified <= cutoff)
+      .unwrap_or(false),
+    None => true,
+  }
+}
+
+fn trash_deletion_date(info_path: &Path) -> Option<SystemTime> {
+  let content = fs::read_to_string(info_path).ok()?;
+  let value = content
+    .lines()
+    .find_map(|line| line.strip_prefix("DeletionDate="))?;
+  let naive = NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%dT%H:%M:%S").ok()?;
+  let local = Local.from_local_datetime(&naive).earliest()?;
+  Some(local.into())
+}
+
+fn read_png_text(path: &Path, keyword: &str) -> Option<String> {
+  let bytes = fs::read(path).ok()?;
+  if bytes.len() < 8 || &bytes[..8] != b"\x89PNG\r\n\x1a\n" {
+    return None;
+  }
+
+  let mut offset = 8;
+  while offset + 8 <= bytes.len() {
+    let length = u32::from_be_bytes(bytes[offset..offset + 4].try_into().ok()?) as usize;
+    let chunk_type = &bytes[offset + 4..offset + 8];
+    let data = bytes.get(offset + 8..offset + 8 + length)?;
+    if chunk_type == b"tEXt" {
+      let separator = data.iter().position(|&b| b == 0)?;
+      if &data[..separator] == keyword.as_bytes() {
+        return Some(String::from_utf8_lossy(&data[separator + 1..]).into_owned());
+      }
+    } else if chunk_type == b"IEND" {
+      break;
+    }
+    offset += length + 12;
+  }
+
+  None
+}
+
+fn decode_file_uri(uri: &str) -> Option<PathBuf> {
+  let encoded = uri.strip_prefix("file://")?.as_bytes();
+  let mut decoded = Vec::with_capacity(encoded.len());
+  let mut i = 0;
+  while i < encoded.len() {
+    if encoded[i] == b'%' && i + 2 < encoded.len() {
+      let hex = std::str::from_utf8(&encoded[i + 1..i + 3]).ok()?;
+      decoded.push(u8::from_str_radix(hex, 16).ok()?);
+      i += 3;
+    } else {
+      decoded.push(encoded[i]);
+      i += 1;
+    }
+  }
+  Some(PathBuf::from(OsStr::from_bytes(&decoded)))
+}
+
+fn path_size(path: &Path) -> u64 {
+  WalkDir::new(path)
+    .into_iter()
+    .filter_map(|e| e.ok())
+    .filter_map(|e| e.metadata().ok())
+    .filter(|m| !m.is_dir())
+    .map(|m| m.len())
+    .sum()
+}
+
+fn resolve_within(path: &str, roots: &[PathBuf]) -> Result<PathBuf, AppError> {
+  let path = Path::new(path);
+  let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+    return Err(AppError::invalidInput(format!(
+      "{} is not a file path",
+      path.display()
+    )));
+  };
+  // Resolve the parent only, so a symlink is removed itself rather than its target
+  let parent = parent
+    .canonicalize()
+    .map_err(|e| AppError::io(path.display(), &e))?;
+  if !roots
+    .iter()
+    .filter_map(|root| root.canonicalize().ok())
+    .any(|root| parent.starts_with(root))
+  {
+    return Err(AppError::new(
+      ErrorCode::OutsideAllowedRoot,
+      format!("{} is outside the allowed directories", path.display()),
+    ));
+  }
+  Ok(parent.join(name))
+}
+
+fn remove_selected_files(paths: Vec<String>, roots: &[PathBuf]) -> BatchResultModel {
+  let mut batch = BatchResultModel::default();
+  for path in paths {
+    let result = resolve_within(&path, roots).and_then(|resolved| {
+      fs::remove_file(&resolved).map_err(|e| AppError::io(resolved.display(), &e))
+    });
+    batch.record(path, result);
+  }
+  batch
+}
+
+/// Deletes rotated logs and truncates active ones through the privileged helper, which
+/// checks every path again. Accounting databases and journal files are refused before
+/// asking it.
+fn clean_log_files(paths: Vec<String>, log_dir: &Path) -> BatchResultModel {
+  let mut batch = BatchResultModel::default();
+  let mut allowed = Vec::new();
+  for path in paths {
+    let result = resolve_within(&path, &[log_dir.to_path_buf()]).and_then(|resolved| {
+      match log_file_kind(&resolved) {
+        LogFileKind::Accounting => Err(AppError::invalidInput(format!(
+          "{} is a login accounting database and is never cleaned",
+          resolved.display()
+        ))),
+        LogFileKind::Journal => Err(AppError::invalidInput(format!(
+          "{} is a journal file; vacuum the journal instead",
+          resolved.display()
+        ))),
+        _ => Ok(resolved),
+      }
+    });
+    match result {
+      Ok(resolved) => allowed.push((path, resolved.to_string_lossy().to_string())),
+      Err(error) => batch.record(path, Err(error)),
+    }
+  }
+  if allowed.is_empty() {
+    return batch;
+  }
+
+  let resolved: Vec<String> = allowed
+    .iter()
+    .map(|(_, resolved)| resolved.clone())
+    .collect();
+  match HelperService::connect().and_then(|helper| helper.cleanLogs(&resolved)) {
+    Ok(items) => {
+      for ((path, _), item) in allowed.into_iter().zip(items) {
+        batch.record(path, item.toResult());
+      }
+    }
+    Err(error) => {
+      for (path, _) in allowed {
+        batch.record(path, Err(error.clone()));
+      }
+    }
+  }
+  batch
+}
+
+fn remove_path(path: &Path) -> io::Result<()> {
+  if fs::symlink_metadata(path)?.is_dir() {
+    fs::remove_dir_all(path)
+  } else {
+    fs::remove_file(path)
+  }
+}
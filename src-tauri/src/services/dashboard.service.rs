@@ -1,11 +1,17 @@
 /* sys lib */
 use std::fs;
 use std::path::Path;
-use std::process::Command;
+
+/* services */
+use crate::services::journal_service::{journal_usage, read_journal};
+use crate::services::log_service::log_file_kind;
+use crate::services::systemd_service::SystemdService;
 
 /* models */
 use crate::models::{
-  DataValue, ResponseModel, ResponseStatus, ScanSummaryModel, SystemServiceModel,
+  AppError, DataValue, FailedUnitModel, JournalQueryModel, LogFileKind, LogSummaryModel,
+  ResponseModel, ResponseStatus, ScanSummaryModel, ServiceScope, ServiceSummaryModel,
+  SettingsModel,
 };
 
 /* helpers */
@@ -13,51 +19,20 @@ use rayon::prelude::*;
 use serde_json::json;
 use walkdir::WalkDir;
 
-pub struct DashboardService;
+const FAILED_UNIT_LOG_LINES: usize = 10;
+
+pub struct DashboardService {
+  settings: SettingsModel,
+}
 
 #[allow(non_snake_case)]
 impl DashboardService {
-  pub fn getRunningServices(&self) -> Result<ResponseModel, ResponseModel> {
-    let output = Command::new("systemctl")
-      .args([
-        "list-units",
-        "--type=service",
-        "--state=running",
-        "--no-pager",
-        "--plain",
-      ])
-      .output()
-      .map_err(|e| format!("Failed to run systemctl: {}", e))?;
-
-    if !output.status.success() {
-      return Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: String::from_utf8_lossy(&output.stderr).to_string(),
-        data: DataValue::String("".to_string()),
-      });
-    }
+  pub fn new(settings: SettingsModel) -> Self {
+    Self { settings }
+  }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut services = Vec::new();
-
-    for line in stdout.lines().skip(1) {
-      let parts: Vec<&str> = line.split_whitespace().collect();
-      if parts.len() >= 4 {
-        let name = parts[0].to_string();
-        let status = parts[3].to_string();
-        let description = if parts.len() > 4 {
-          parts[4..].join(" ")
-        } else {
-          "No description".to_string()
-        };
-        services.push(SystemServiceModel {
-          name,
-          description,
-          status: status.clone(),
-          isRunning: status == "running",
-        });
-      }
-    }
+  pub fn getRunningServices(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    let services = SystemdService::connect(scope)?.listServices(&["running"])?;
 
     Ok(ResponseModel {
       status: ResponseStatus::Success,
@@ -71,15 +46,83 @@ impl DashboardService {
     })
   }
 
-  pub fn getCacheSummary(&self) -> Result<ResponseModel, ResponseModel> {
-    let cacheDir = dirs::cache_dir().ok_or("Cache directory not found")?;
+  pub fn getFailedUnits(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    let systemd = SystemdService::connect(scope)?;
+    let mut units = Vec::new();
+    let mut incomplete = 0;
+    for unit in systemd.listUnits(&["failed"], &[])? {
+      // A unit unloaded meanwhile, or one whose properties cannot be read, is still failed
+      let mut failed = match systemd.getFailedUnit(&unit.name) {
+        Ok(failed) => failed,
+        Err(error) => {
+          incomplete += 1;
+          FailedUnitModel {
+            unit: unit.clone(),
+            result: String::new(),
+            exitStatus: None,
+            exitSignal: None,
+            failedAt: None,
+            recentLogs: Vec::new(),
+            error: Some(error.message),
+          }
+        }
+      };
+      let query = JournalQueryModel {
+        unit: unit.name,
+        scope,
+        lines: Some(FAILED_UNIT_LOG_LINES),
+        priority: None,
+        since: None,
+        until: None,
+      };
+      // Reading the system journal may need group membership; the failure itself is enough
+      failed.recentLogs = read_journal(&query).unwrap_or_default();
+      units.push(serde_json::to_value(failed).unwrap_or(json!({})));
+    }
+
+    let mut message = format!("Found {} failed units", units.len());
+    if incomplete > 0 {
+      message.push_str(&format!("; details unavailable for {}", incomplete));
+    }
+
+    Ok(ResponseModel {
+      status: if incomplete > 0 {
+        ResponseStatus::Warning
+      } else {
+        ResponseStatus::Success
+      },
+      message,
+      data: DataValue::Array(units),
+    })
+  }
+
+  pub fn getServiceSummary(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    let systemd = SystemdService::connect(scope)?;
+    let summary = ServiceSummaryModel {
+      running: systemd.listServices(&["running"])?.len(),
+      failed: systemd.listUnits(&["failed"], &[])?.len(),
+    };
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: "Service summary retrieved successfully".to_string(),
+      data: DataValue::Object(serde_json::to_value(summary).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn getCacheSummary(&self) -> Result<ResponseModel, AppError> {
+    let cacheDir = self
+      .settings
+      .cache
+      .resolveCacheDir()
+      .ok_or_else(|| AppError::notFound("Cache directory not found"))?;
 
     let (totalSize, fileCount) = WalkDir::new(cacheDir)
-      .max_depth(4)
+      .max_depth(self.settings.cache.maxDepth)
       .into_iter()
       .filter_map(|e| e.ok())
       .filter(|e| e.file_type().is_file())
-      .take(2000)
+      .take(self.settings.cache.summaryLimit)
       .collect::<Vec<_>>()
       .into_par_iter()
       .filter_map(|entry| fs::metadata(entry.path()).ok())
@@ -105,8 +148,8 @@ impl DashboardService {
     })
   }
 
-  pub fn getTrashSummary(&self) -> Result<ResponseModel, ResponseModel> {
-    let home = dirs::home_dir().ok_or("Home directory not found")?;
+  pub fn getTrashSummary(&self) -> Result<ResponseModel, AppError> {
+    let home = dirs::home_dir().ok_or_else(|| AppError::notFound("Home directory not found"))?;
     let trashDir = home.join(".local/share/Trash/files");
     let mut totalSize = 0;
     let mut fileCount = 0;
@@ -132,15 +175,15 @@ impl DashboardService {
     })
   }
 
-  pub fn getLogSummary(&self) -> Result<ResponseModel, ResponseModel> {
-    let logDir = Path::new("/var/log");
+  pub fn getLogSummary(&self) -> Result<ResponseModel, AppError> {
+    let logDir = Path::new(&self.settings.logs.logDir);
 
     let (totalSize, fileCount) = WalkDir::new(logDir)
-      .max_depth(2)
+      .max_depth(self.settings.logs.summaryMaxDepth)
       .into_iter()
       .filter_map(|e| e.ok())
-      .filter(|e| e.file_type().is_file())
-      .take(500)
+      .filter(|e| e.file_type().is_file() && log_file_kind(e.path()) != LogFileKind::Journal)
+      .take(self.settings.logs.listLimit)
       .collect::<Vec<_>>()
       .into_par_iter()
       .filter_map(|entry| fs::metadata(entry.path()).ok())
@@ -154,9 +197,14 @@ impl DashboardService {
       )
       .reduce(|| (0u64, 0usize), |a, b| (a.0 + b.0, a.1 + b.1));
 
-    let summary = ScanSummaryModel {
-      totalSize,
-      fileCount,
+    // Journal files are counted once, from wherever journald keeps them
+    let journal = journal_usage();
+    let summary = LogSummaryModel {
+      files: ScanSummaryModel {
+        totalSize: totalSize + journal.totalSize,
+        fileCount: fileCount + journal.fileCount,
+      },
+      journal,
     };
 
     Ok(ResponseModel {
@@ -166,17 +214,10 @@ impl DashboardService {
     })
   }
 
-  pub fn getLargeFilesSummary(&self) -> Result<ResponseModel, ResponseModel> {
-    let home = dirs::home_dir().ok_or("Home directory not found")?;
-    let threshold = 100 * 1024 * 1024;
-
-    let dirsToScan = vec![
-      home.join("Downloads"),
-      home.join("Documents"),
-      home.join("Videos"),
-      home.join("Pictures"),
-      home.join("Desktop"),
-    ];
+  pub fn getLargeFilesSummary(&self) -> Result<ResponseModel, AppError> {
+    let largeFiles = &self.settings.largeFiles;
+    let threshold = largeFiles.thresholdBytes;
+    let dirsToScan = largeFiles.resolveRoots();
 
     let (totalSize, fileCount) = dirsToScan
       .into_par_iter()
@@ -185,7 +226,7 @@ impl DashboardService {
           return (0u64, 0usize);
         }
         WalkDir::new(dir)
-          .max_depth(3)
+          .max_depth(largeFiles.maxDepth)
           .into_iter()
           .filter_map(|e| e.ok())
           .filter(|e| e.file_type().is_file())
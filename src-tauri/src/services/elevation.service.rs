@@ -0,0 +1,238 @@
+/* sys lib */
+use std::sync::{Arc, Mutex};
+
+/* services */
+use crate::services::helper_service::HelperService;
+use crate::services::package_service::detect_package_manager;
+
+/* models */
+use crate::models::{
+  AppError, BatchResultModel, DataValue, ElevationStatusModel, ItemResultModel,
+  PrivilegedOperationModel, PrivilegedRunModel, ResponseModel, ResponseStatus,
+};
+
+/* helpers */
+use serde_json::json;
+
+/// Item names reported for a journal vacuum and a package cache cleaning in a queue run.
+const JOURNAL_ITEM: &str = "journal";
+const PACKAGE_CACHE_ITEM: &str = "packageCache";
+
+/// Privileged operations waiting to run together in one elevated session.
+#[derive(Clone, Default)]
+pub struct PrivilegedQueueState(Arc<Mutex<Vec<PrivilegedOperationModel>>>);
+
+impl PrivilegedQueueState {
+  fn list(&self) -> Vec<PrivilegedOperationModel> {
+    self.0.lock().map(|queue| queue.clone()).unwrap_or_default()
+  }
+
+  fn extend(&self, operations: Vec<PrivilegedOperationModel>) {
+    if let Ok(mut queue) = self.0.lock() {
+      queue.extend(operations);
+    }
+  }
+
+  /// Puts operations that could not run back in front of anything queued meanwhile.
+  fn restore(&self, operations: Vec<PrivilegedOperationModel>) {
+    if let Ok(mut queue) = self.0.lock() {
+      queue.splice(0..0, operations);
+    }
+  }
+
+  fn take(&self) -> Vec<PrivilegedOperationModel> {
+    self
+      .0
+      .lock()
+      .map(|mut queue| std::mem::take(&mut *queue))
+      .unwrap_or_default()
+  }
+}
+
+pub struct ElevationService {
+  queue: PrivilegedQueueState,
+}
+
+#[allow(non_snake_case)]
+impl ElevationService {
+  pub fn new(queue: PrivilegedQueueState) -> Self {
+    Self { queue }
+  }
+
+  pub fn getElevationStatus(&self) -> Result<ResponseModel, AppError> {
+    let status = self.status();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: if status.elevated {
+        format!("Elevated for another {} seconds", status.expiresIn)
+      } else {
+        "Not elevated".to_string()
+      },
+      data: DataValue::Object(serde_json::to_value(status).unwrap_or(json!({}))),
+    })
+  }
+
+  /// Authenticates once and opens an elevated session in the privileged helper; service
+  /// actions, log clearing and package cache cleaning then run through it without asking
+  /// again.
+  pub fn elevate(&self) -> Result<ResponseModel, AppError> {
+    let expiresIn = HelperService::connect()?.beginSession()?;
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "Elevated; the session ends after {} idle minutes",
+        expiresIn / 60
+      ),
+      data: DataValue::Object(serde_json::to_value(self.status()).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn dropPrivileges(&self) -> Result<ResponseModel, AppError> {
+    let helper = HelperService::connect()?;
+    if helper.isRunning() {
+      helper.endSession()?;
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: "Privileges dropped".to_string(),
+      data: DataValue::Object(serde_json::to_value(self.status()).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn queuePrivilegedOperations(
+    &self,
+    operations: Vec<PrivilegedOperationModel>,
+  ) -> Result<ResponseModel, AppError> {
+    let empty = operations.iter().any(|operation| match operation {
+      PrivilegedOperationModel::Service { units, .. } => units.is_empty(),
+      PrivilegedOperationModel::CleanLogs { paths } => paths.is_empty(),
+      PrivilegedOperationModel::RemovePackages { packages, .. } => packages.is_empty(),
+      PrivilegedOperationModel::RemoveKernels { versions } => versions.is_empty(),
+      PrivilegedOperationModel::VacuumJournal(_)
+      | PrivilegedOperationModel::CleanPackageCache(_) => false,
+    });
+    if empty {
+      return Err(AppError::invalidInput(
+        "Every queued operation needs at least one target",
+      ));
+    }
+    let count = operations.len();
+    self.queue.extend(operations);
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Queued {} privileged operations", count),
+      data: DataValue::Object(serde_json::to_value(self.status()).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn clearPrivilegedQueue(&self) -> Result<ResponseModel, AppError> {
+    let removed = self.queue.take();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Removed {} queued operations", removed.len()),
+      data: DataValue::Object(serde_json::to_value(self.status()).unwrap_or(json!({}))),
+    })
+  }
+
+  /// Runs every queued operation in one elevated session, authenticating first only if
+  /// no session is open. Package and kernel removals still ask each time.
+  pub fn runPrivilegedQueue(&self) -> Result<ResponseModel, AppError> {
+    let operations = self.queue.take();
+    if operations.is_empty() {
+      return Ok(ResponseModel {
+        status: ResponseStatus::Info,
+        message: "No privileged operations queued".to_string(),
+        data: DataValue::String("".to_string()),
+      });
+    }
+
+    let helper = match HelperService::connect().and_then(|helper| {
+      if !helper.isRunning() || helper.sessionStatus()? == 0 {
+        helper.beginSession()?;
+      }
+      Ok(helper)
+    }) {
+      Ok(helper) => helper,
+      Err(error) => {
+        self.queue.restore(operations);
+        return Err(error);
+      }
+    };
+
+    let mut batch = BatchResultModel::default();
+    for operation in &operations {
+      let (targets, results) = match operation {
+        PrivilegedOperationModel::Service { action, units } => {
+          (units.clone(), helper.manageUnits(*action, units))
+        }
+        PrivilegedOperationModel::CleanLogs { paths } => (paths.clone(), helper.cleanLogs(paths)),
+        PrivilegedOperationModel::VacuumJournal(vacuum) => {
+          let result = helper
+            .vacuumJournal(vacuum)
+            .map(|_| vec![ItemResultModel::succeeded(JOURNAL_ITEM)]);
+          (vec![JOURNAL_ITEM.to_string()], result)
+        }
+        PrivilegedOperationModel::CleanPackageCache(clean) => {
+          let manager = clean.manager.map_or_else(detect_package_manager, Ok);
+          let item = manager
+            .as_ref()
+            .map_or(PACKAGE_CACHE_ITEM, |manager| manager.as_str());
+          let result = manager
+            .and_then(|manager| helper.cleanPackageCache(manager, clean.keepVersions))
+            .map(|_| vec![ItemResultModel::succeeded(item)]);
+          (vec![item.to_string()], result)
+        }
+        PrivilegedOperationModel::RemovePackages { manager, packages } => {
+          let result = manager
+            .map_or_else(detect_package_manager, Ok)
+            .and_then(|manager| helper.removePackages(manager, packages));
+          (packages.clone(), result)
+        }
+        PrivilegedOperationModel::RemoveKernels { versions } => {
+          (versions.clone(), helper.removeKernels(versions))
+        }
+      };
+      match results {
+        Ok(items) => items.into_iter().for_each(|item| batch.push(item)),
+        Err(error) => {
+          for target in targets {
+            batch.record(target, Err(error.clone()));
+          }
+        }
+      }
+    }
+
+    let error = batch.toError("Privileged operations completed:");
+    let succeeded = batch.succeeded;
+    let result = PrivilegedRunModel { batch, operations };
+    let data = DataValue::Object(serde_json::to_value(result).unwrap_or(json!({})));
+    if let Some(error) = error {
+      return Err(error.withData(data));
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Completed {} privileged operations", succeeded),
+      data,
+    })
+  }
+
+  fn status(&self) -> ElevationStatusModel {
+    let expiresIn = HelperService::connect()
+      .ok()
+      .filter(|helper| helper.isRunning())
+      .and_then(|helper| helper.sessionStatus().ok())
+      .unwrap_or(0);
+
+    ElevationStatusModel {
+      elevated: expiresIn > 0,
+      expiresIn,
+      queue: self.queue.list(),
+    }
+  }
+}
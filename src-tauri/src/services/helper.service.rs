@@ -0,0 +1,216 @@
+/* sys lib */
+use std::sync::OnceLock;
+
+/* services */
+use crate::services::systemd_service::dbus_error;
+
+/* models */
+use crate::models::{
+  AppError, ErrorCode, ItemResultModel, JournalVacuumModel, PackageManager, ServiceAction,
+};
+
+/* helpers */
+use zbus::blocking::fdo::DBusProxy;
+use zbus::blocking::{Connection, Proxy};
+use zbus::names::BusName;
+use zbus::proxy::MethodFlags;
+
+pub const HELPER_BUS_NAME: &str = "com.tcs.cleanux.Helper";
+pub const HELPER_PATH: &str = "/com/tcs/cleanux/Helper";
+pub const HELPER_INTERFACE: &str = "com.tcs.cleanux.Helper1";
+
+/// Per-item result as sent over the bus: item, success, error code and message.
+pub type ItemReply = (String, bool, String, String);
+
+/// The system bus connection every helper call goes through. The helper keys elevated
+/// sessions by the caller's unique bus name, and each new connection gets a new one.
+static CONNECTION: OnceLock<Connection> = OnceLock::new();
+
+/// Client of the D-Bus activated helper that runs the few operations needing root. Calls
+/// are authorized by polkit in the helper, so the caller may be asked to authenticate
+/// unless it has an elevated session open.
+pub struct HelperService {
+  connection: Connection,
+}
+
+#[allow(non_snake_case)]
+impl HelperService {
+  pub fn connect() -> Result<Self, AppError> {
+    if let Some(connection) = CONNECTION.get() {
+      return Ok(Self {
+        connection: connection.clone(),
+      });
+    }
+    let connection =
+      Connection::system().map_err(|e| dbus_error("Failed to connect to the system bus", e))?;
+    Ok(Self {
+      connection: CONNECTION.get_or_init(|| connection).clone(),
+    })
+  }
+
+  /// Returns a connection to the helper when it is running with an elevated session open
+  /// for this process. It never activates the helper just to find out.
+  pub fn activeSession() -> Option<Self> {
+    let helper = Self::connect().ok()?;
+    if !helper.isRunning() || helper.sessionStatus().ok()? == 0 {
+      return None;
+    }
+    Some(helper)
+  }
+
+  pub fn isRunning(&self) -> bool {
+    let Ok(name) = BusName::from_static_str(HELPER_BUS_NAME) else {
+      return false;
+    };
+    DBusProxy::new(&self.connection)
+      .ok()
+      .and_then(|proxy| proxy.name_has_owner(name).ok())
+      .unwrap_or(false)
+  }
+
+  /// Authenticates once and opens an elevated session, during which the helper runs
+  /// requests from this process without asking again, except package and kernel removal.
+  /// Returns the seconds it lasts.
+  pub fn beginSession(&self) -> Result<u64, AppError> {
+    self.call("beginSession", "Failed to elevate", &())
+  }
+
+  pub fn endSession(&self) -> Result<(), AppError> {
+    self.call("endSession", "Failed to drop privileges", &())
+  }
+
+  /// Seconds left in this process's elevated session, or 0 without one.
+  pub fn sessionStatus(&self) -> Result<u64, AppError> {
+    self.call("sessionStatus", "Failed to query the elevated session", &())
+  }
+
+  /// Deletes rotated logs and truncates active ones under `/var/log`, returning one result
+  /// per path.
+  pub fn cleanLogs(&self, paths: &[String]) -> Result<Vec<ItemResultModel>, AppError> {
+    let replies: Vec<ItemReply> = self.call("cleanLogs", "Failed to clean log files", &(paths,))?;
+    Ok(replies.into_iter().map(item_from_reply).collect())
+  }
+
+  /// Vacuums the journal through journalctl, returning what it reported.
+  pub fn vacuumJournal(&self, vacuum: &JournalVacuumModel) -> Result<String, AppError> {
+    let maxBytes = vacuum.maxSize.unwrap_or(0);
+    let maxAgeSeconds = u64::from(vacuum.maxAgeDays.unwrap_or(0)) * 86_400;
+    let maxFiles = vacuum.maxFiles.unwrap_or(0);
+    if maxBytes == 0 && maxAgeSeconds == 0 && maxFiles == 0 {
+      return Err(AppError::invalidInput(
+        "A size, age or file count limit is required",
+      ));
+    }
+    self.call(
+      "vacuumJournal",
+      "Failed to vacuum the journal",
+      &(maxBytes, maxAgeSeconds, maxFiles),
+    )
+  }
+
+  /// Cleans the package manager's download cache, returning what the tool reported.
+  pub fn cleanPackageCache(
+    &self,
+    manager: PackageManager,
+    keepVersions: Option<u32>,
+  ) -> Result<String, AppError> {
+    let keepVersions = keepVersions.map_or(-1, |keep| i32::try_from(keep).unwrap_or(i32::MAX));
+    self.call(
+      "cleanPackageCache",
+      &format!("Failed to clean the {} cache", manager.as_str()),
+      &(manager.as_str(), keepVersions),
+    )
+  }
+
+  /// Removes packages that nothing requires anymore, returning one result per package.
+  pub fn removePackages(
+    &self,
+    manager: PackageManager,
+    packages: &[String],
+  ) -> Result<Vec<ItemResultModel>, AppError> {
+    let replies: Vec<ItemReply> = self.call(
+      "removePackages",
+      &format!("Failed to remove {}", packages.join(", ")),
+      &(manager.as_str(), packages),
+    )?;
+    Ok(replies.into_iter().map(item_from_reply).collect())
+  }
+
+  /// Uninstalls old kernels, returning one result per kernel version.
+  pub fn removeKernels(&self, versions: &[String]) -> Result<Vec<ItemResultModel>, AppError> {
+    let replies: Vec<ItemReply> = self.call(
+      "removeKernels",
+      &format!("Failed to remove kernels {}", versions.join(", ")),
+      &(versions,),
+    )?;
+    Ok(replies.into_iter().map(item_from_reply).collect())
+  }
+
+  /// Applies an action to system units as root, returning one result per unit.
+  pub fn manageUnits(
+    &self,
+    action: ServiceAction,
+    units: &[String],
+  ) -> Result<Vec<ItemResultModel>, AppError> {
+    let action = serde_json::to_value(action)
+      .ok()
+      .and_then(|value| value.as_str().map(str::to_string))
+      .unwrap_or_default();
+    let replies: Vec<ItemReply> = self.call(
+      "manageUnits",
+      &format!("Failed to {} {}", action, units.join(", ")),
+      &(&action, units),
+    )?;
+    Ok(replies.into_iter().map(item_from_reply).collect())
+  }
+
+  fn call<B, R>(&self, method: &str, context: &str, body: &B) -> Result<R, AppError>
+  where
+    B: serde::Serialize + zbus::zvariant::DynamicType,
+    R: for<'d> zbus::zvariant::DynamicDeserialize<'d>,
+  {
+    let proxy = Proxy::new(
+      &self.connection,
+      HELPER_BUS_NAME,
+      HELPER_PATH,
+      HELPER_INTERFACE,
+    )
+    .map_err(|e| dbus_error("Failed to reach the privileged helper", e))?;
+    proxy
+      .call_with_flags(method, MethodFlags::AllowInteractiveAuth.into(), body)
+      .map_err(|e| match &e {
+        // The bus cannot activate a helper whose service file was never installed, as
+        // with the AppImage
+        zbus::Error::MethodError(name, _, _)
+          if name.as_str() == "org.freedesktop.DBus.Error.ServiceUnknown" =>
+        {
+          AppError::notFound(format!(
+            "{}: the privileged helper is not installed; install Cleanux from its deb or rpm \
+             package to use privileged operations",
+            context
+          ))
+        }
+        _ => dbus_error(context, e),
+      })?
+      .ok_or_else(|| AppError::internal(format!("The helper returned nothing for {}", method)))
+  }
+}
+
+pub fn item_reply(item: ItemResultModel) -> ItemReply {
+  let code = item.code.map(|code| code.to_string());
+  (
+    item.item,
+    item.success,
+    code.unwrap_or_default(),
+    item.message.unwrap_or_default(),
+  )
+}
+
+fn item_from_reply((item, success, code, message): ItemReply) -> ItemResultModel {
+  if success {
+    return ItemResultModel::succeeded(item);
+  }
+  let code =
+    serde_json::from_value(serde_json::Value::String(code)).unwrap_or(ErrorCode::CommandFailed);
+  ItemResultModel::failed(item, AppError::new(code, message))
+}
@@ -0,0 +1,231 @@
+/* sys lib */
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/* services */
+use crate::services::systemd_service::format_timestamp;
+
+/* models */
+use crate::models::{
+  AppError, DataValue, JournalEntryModel, JournalQueryModel, JournalUsageModel, ResponseModel,
+  ResponseStatus, ServiceScope,
+};
+
+/* helpers */
+use serde_json::{json, Value};
+use walkdir::WalkDir;
+
+const DEFAULT_LINES: usize = 200;
+const MAX_LINES: usize = 10_000;
+/// Persistent and volatile journal storage.
+const JOURNAL_DIRS: [&str; 2] = ["/var/log/journal", "/run/log/journal"];
+
+/// Running `journalctl --follow` processes, keyed by their pid.
+#[derive(Clone, Default)]
+pub struct JournalFollowState(Arc<Mutex<HashMap<u32, Child>>>);
+
+impl JournalFollowState {
+  fn insert(&self, child: Child) -> u32 {
+    let id = child.id();
+    if let Ok(mut follows) = self.0.lock() {
+      follows.insert(id, child);
+    }
+    id
+  }
+
+  fn remove(&self, id: u32) -> Option<Child> {
+    self.0.lock().ok()?.remove(&id)
+  }
+}
+
+pub struct JournalService {
+  follows: JournalFollowState,
+}
+
+#[allow(non_snake_case)]
+impl JournalService {
+  pub fn new(follows: JournalFollowState) -> Self {
+    Self { follows }
+  }
+
+  pub fn getServiceJournal(&self, query: JournalQueryModel) -> Result<ResponseModel, AppError> {
+    let entries: Vec<Value> = read_journal(&query)?
+      .into_iter()
+      .map(|entry| serde_json::to_value(entry).unwrap_or(json!({})))
+      .collect();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Found {} journal entries for {}", entries.len(), query.unit),
+      data: DataValue::Array(entries),
+    })
+  }
+
+  pub fn followServiceJournal<F>(
+    &self,
+    query: JournalQueryModel,
+    onEntry: F,
+  ) -> Result<ResponseModel, AppError>
+  where
+    F: Fn(u32, JournalEntryModel) + Send + 'static,
+  {
+    let mut child = journal_command(&query)?
+      .arg("--follow")
+      .stdout(Stdio::piped())
+      .stderr(Stdio::null())
+      .spawn()
+      .map_err(|e| AppError::spawn("journalctl", &e))?;
+    let stdout = child
+      .stdout
+      .take()
+      .ok_or_else(|| AppError::internal("journalctl output is not available"))?;
+    let followId = self.follows.insert(child);
+
+    let follows = self.follows.clone();
+    thread::spawn(move || {
+      for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+        if let Some(entry) = parse_entry(&line) {
+          onEntry(followId, entry);
+        }
+      }
+      // journalctl exited on its own; reap it unless stopFollowingJournal already did
+      if let Some(mut child) = follows.remove(followId) {
+        let _ = child.wait();
+      }
+    });
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Following the journal of {}", query.unit),
+      data: DataValue::Object(json!({ "followId": followId })),
+    })
+  }
+
+  pub fn stopFollowingJournal(&self, followId: u32) -> Result<ResponseModel, AppError> {
+    let mut child = self
+      .follows
+      .remove(followId)
+      .ok_or_else(|| AppError::notFound(format!("No journal follow with id {}", followId)))?;
+    let _ = child.kill();
+    let _ = child.wait();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: "Stopped following the journal".to_string(),
+      data: DataValue::String("".to_string()),
+    })
+  }
+}
+
+pub fn read_journal(query: &JournalQueryModel) -> Result<Vec<JournalEntryModel>, AppError> {
+  let output = journal_command(query)?
+    .arg("--no-pager")
+    .output()
+    .map_err(|e| AppError::spawn("journalctl", &e))?;
+  if !output.status.success() {
+    return Err(AppError::command(
+      format!("Failed to read the journal of {}", query.unit),
+      &output,
+    ));
+  }
+
+  Ok(
+    String::from_utf8_lossy(&output.stdout)
+      .lines()
+      .filter_map(parse_entry)
+      .collect(),
+  )
+}
+
+/// Size of the system and user journals. Only the metadata is read, so this works
+/// without access to the journal contents.
+pub fn journal_usage() -> JournalUsageModel {
+  let mut usage = JournalUsageModel::default();
+  let files = JOURNAL_DIRS
+    .iter()
+    .flat_map(|dir| WalkDir::new(dir).max_depth(2))
+    .filter_map(|e| e.ok())
+    .filter(|e| e.file_type().is_file());
+  for entry in files {
+    let name = entry.file_name().to_string_lossy();
+    if !name.ends_with(".journal") && !name.ends_with(".journal~") {
+      continue;
+    }
+    let size = entry.metadata().map_or(0, |m| m.len());
+    usage.totalSize += size;
+    usage.fileCount += 1;
+    // Archived files are renamed to `name@seqnum-...`; the active ones keep a plain name
+    if name.contains('@') {
+      usage.archivedSize += size;
+      usage.archivedCount += 1;
+    }
+  }
+  usage
+}
+
+fn journal_command(query: &JournalQueryModel) -> Result<Command, AppError> {
+  if query.unit.trim().is_empty() {
+    return Err(AppError::invalidInput("A unit name is required"));
+  }
+  if query.priority.is_some_and(|priority| priority > 7) {
+    return Err(AppError::invalidInput(
+      "Priority must be between 0 (emerg) and 7 (debug)",
+    ));
+  }
+
+  let mut command = Command::new("journalctl");
+  command.arg("--output=json");
+  match query.scope {
+    ServiceScope::System => command.arg(format!("--unit={}", query.unit)),
+    ServiceScope::User => command.arg(format!("--user-unit={}", query.unit)),
+  };
+  let lines = query.lines.unwrap_or(DEFAULT_LINES).min(MAX_LINES);
+  command.arg(format!("--lines={}", lines));
+  if let Some(priority) = query.priority {
+    command.arg(format!("--priority={}", priority));
+  }
+  if let Some(since) = query.since.as_deref().filter(|s| !s.is_empty()) {
+    command.arg(format!("--since={}", since));
+  }
+  if let Some(until) = query.until.as_deref().filter(|s| !s.is_empty()) {
+    command.arg(format!("--until={}", until));
+  }
+  Ok(command)
+}
+
+fn parse_entry(line: &str) -> Option<JournalEntryModel> {
+  let value: Value = serde_json::from_str(line).ok()?;
+  let text = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+  let number = |key: &str| text(key).and_then(|s| s.parse::<u64>().ok());
+
+  Some(JournalEntryModel {
+    cursor: text("__CURSOR")?,
+    timestamp: number("__REALTIME_TIMESTAMP")
+      .and_then(format_timestamp)
+      .unwrap_or_default(),
+    priority: number("PRIORITY").map_or(6, |p| p.min(7) as u8),
+    identifier: text("SYSLOG_IDENTIFIER")
+      .or_else(|| text("_COMM"))
+      .unwrap_or_default(),
+    pid: number("_PID").and_then(|pid| u32::try_from(pid).ok()),
+    message: value.get("MESSAGE").map(message_text).unwrap_or_default(),
+  })
+}
+
+/// journalctl encodes messages that are not valid UTF-8 as an array of bytes.
+fn message_text(message: &Value) -> String {
+  match message {
+    Value::String(text) => text.clone(),
+    Value::Array(bytes) => {
+      let bytes: Vec<u8> = bytes
+        .iter()
+        .filter_map(|b| b.as_u64().and_then(|b| u8::try_from(b).ok()))
+        .collect();
+      String::from_utf8_lossy(&bytes).to_string()
+    }
+    _ => String::new(),
+  }
+}
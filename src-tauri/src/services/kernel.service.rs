@@ -0,0 +1,357 @@
+/* sys lib */
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/* services */
+use crate::services::helper_service::HelperService;
+use crate::services::package_service::{detect_package_manager, run_query};
+
+/* models */
+use crate::models::{
+  AppError, BatchResultModel, DataValue, KernelModel, PackageManager, ResponseModel, ResponseStatus,
+};
+
+/* helpers */
+use serde_json::json;
+use walkdir::WalkDir;
+
+const BOOT_DIR: &str = "/boot";
+const MODULES_DIRS: [&str; 2] = ["/lib/modules", "/usr/lib/modules"];
+const OSRELEASE_PATH: &str = "/proc/sys/kernel/osrelease";
+
+pub struct KernelService;
+
+#[allow(non_snake_case)]
+impl KernelService {
+  pub fn getKernels(&self) -> Result<ResponseModel, AppError> {
+    let kernels = installed_kernels();
+    let removable: u64 = kernels
+      .iter()
+      .filter(|kernel| !kernel.protected)
+      .map(|kernel| kernel.totalSize)
+      .sum();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "Found {} kernels; old ones use {} bytes",
+        kernels.len(),
+        removable
+      ),
+      data: DataValue::Array(
+        kernels
+          .into_iter()
+          .map(|k| serde_json::to_value(k).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
+  }
+
+  /// Uninstalls the packages of the selected kernels through the privileged helper, which
+  /// refuses the running and latest kernels.
+  pub fn removeKernels(&self, versions: Vec<String>) -> Result<ResponseModel, AppError> {
+    if versions.is_empty() {
+      return Ok(ResponseModel {
+        status: ResponseStatus::Success,
+        message: "No kernels selected".to_string(),
+        data: DataValue::String("".to_string()),
+      });
+    }
+
+    let mut batch = BatchResultModel::default();
+    for item in HelperService::connect()?.removeKernels(&versions)? {
+      batch.push(item);
+    }
+    if let Some(error) = batch.toError("Removed") {
+      return Err(error);
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Removed {} kernels", batch.succeeded),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
+  }
+}
+
+/// Kernels with a modules directory or an image in `/boot`, newest first.
+pub fn installed_kernels() -> Vec<KernelModel> {
+  let running = fs::read_to_string(OSRELEASE_PATH)
+    .map(|release| release.trim().to_string())
+    .unwrap_or_default();
+  let modules_dir = MODULES_DIRS
+    .iter()
+    .map(PathBuf::from)
+    .find(|dir| dir.is_dir())
+    .unwrap_or_else(|| PathBuf::from(MODULES_DIRS[0]));
+  let boot_names: Vec<String> = fs::read_dir(BOOT_DIR)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect()
+    })
+    .unwrap_or_default();
+
+  let mut versions: BTreeSet<String> = fs::read_dir(&modules_dir)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect()
+    })
+    .unwrap_or_default();
+  versions.extend(
+    boot_names
+      .iter()
+      .filter_map(|name| boot_image_version(name))
+      .map(str::to_string),
+  );
+  let latest = versions
+    .iter()
+    .max_by(|a, b| compare_versions(a, b))
+    .cloned()
+    .unwrap_or_default();
+  let manager = detect_package_manager().ok();
+
+  let mut kernels: Vec<KernelModel> = versions
+    .into_iter()
+    .map(|version| {
+      let boot_files: Vec<PathBuf> = boot_names
+        .iter()
+        .filter(|name| is_boot_file_of(name, &version))
+        .map(|name| Path::new(BOOT_DIR).join(name))
+        .collect();
+      let boot_size = boot_files
+        .iter()
+        .filter_map(|path| fs::symlink_metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum();
+      let modules = modules_dir.join(&version);
+      let modules_size = WalkDir::new(&modules)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum();
+      let owned_paths: Vec<PathBuf> = [
+        modules.clone(),
+        modules.join("vmlinuz"),
+        Path::new(BOOT_DIR).join(format!("vmlinuz-{}", version)),
+      ]
+      .into_iter()
+      .filter(|path| path.exists())
+      .collect();
+
+      let is_running = version == running;
+      let is_latest = version == latest;
+      KernelModel {
+        packages: manager.map_or_else(Vec::new, |manager| owning_packages(manager, &owned_paths)),
+        running: is_running,
+        latest: is_latest,
+        protected: is_running || is_latest,
+        bootFiles: boot_files
+          .iter()
+          .map(|path| path.to_string_lossy().to_string())
+          .collect(),
+        bootSize: boot_size,
+        modulesSize: modules_size,
+        totalSize: boot_size + modules_size,
+        version,
+      }
+    })
+    .collect();
+  kernels.sort_by(|a, b| compare_versions(&b.version, &a.version));
+  kernels
+}
+
+/// Version of a kernel image in `/boot`. Fedora's rescue image, `vmlinuz-0-rescue-<id>`,
+/// is a copy made by the bootloader tooling rather than an installed kernel.
+fn boot_image_version(name: &str) -> Option<&str> {
+  let version = name.strip_prefix("vmlinuz-")?;
+  let kernel =
+    version.starts_with(|c: char| c.is_ascii_digit()) && !version.starts_with("0-rescue-");
+  kernel.then_some(version)
+}
+
+/// Whether a file in `/boot` belongs to the kernel, such as `vmlinuz-<version>`,
+/// `initrd.img-<version>` or `initramfs-<version>.img`. The prefixes never contain a dash,
+/// so the version always follows the first one.
+fn is_boot_file_of(name: &str, version: &str) -> bool {
+  let Some(rest) = name
+    .split_once('-')
+    .and_then(|(_, rest)| rest.strip_prefix(version))
+  else {
+    return false;
+  };
+  rest.is_empty() || rest.starts_with('.') || rest == "-kdump.img"
+}
+
+/// Packages owning any of the paths, as the package manager records them.
+fn owning_packages(manager: PackageManager, paths: &[PathBuf]) -> Vec<String> {
+  if paths.is_empty() {
+    return Vec::new();
+  }
+  let paths: Vec<String> = paths
+    .iter()
+    .map(|path| path.to_string_lossy().to_string())
+    .collect();
+  let (program, options): (&str, &[&str]) = match manager {
+    PackageManager::Apt => ("dpkg-query", &["-S"]),
+    PackageManager::Dnf | PackageManager::Zypper => ("rpm", &["-qf"]),
+    PackageManager::Pacman => ("pacman", &["-Qoq"]),
+  };
+  let mut args = options.to_vec();
+  args.extend(paths.iter().map(String::as_str));
+  // Paths owned by no package are reported on stderr or as lines with spaces, and skipped
+  let Ok(output) = run_query(program, &args) else {
+    return Vec::new();
+  };
+  let text = String::from_utf8_lossy(&output.stdout);
+
+  let packages: BTreeSet<String> = match manager {
+    // Lines such as `linux-image-6.1.0-13-amd64, linux-modules-6.1.0-13-amd64: /path`
+    PackageManager::Apt => text
+      .lines()
+      .filter(|line| !line.starts_with("diversion "))
+      .filter_map(|line| line.split_once(": "))
+      .flat_map(|(packages, _)| packages.split(", ").map(str::to_string))
+      .collect(),
+    _ => text
+      .lines()
+      .map(str::trim)
+      .filter(|line| !line.is_empty() && !line.contains(char::is_whitespace))
+      .map(str::to_string)
+      .collect(),
+  };
+  packages.into_iter().collect()
+}
+
+/// Orders kernel releases by their numeric parts, so `6.8.0-45` sorts after `6.8.0-9`.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+  let (mut a, mut b) = (a, b);
+  loop {
+    match (a.is_empty(), b.is_empty()) {
+      (true, true) => return Ordering::Equal,
+      (true, false) if is_prerelease(b) => return Ordering::Greater,
+      (true, false) => return Ordering::Less,
+      (false, true) if is_prerelease(a) => return Ordering::Less,
+      (false, true) => return Ordering::Greater,
+      _ => {}
+    }
+    let (a_part, a_rest) = split_version_part(a);
+    let (b_part, b_rest) = split_version_part(b);
+    let order = match (a_part.parse::<u64>(), b_part.parse::<u64>()) {
+      (Ok(x), Ok(y)) => x.cmp(&y),
+      _ => a_part.cmp(b_part),
+    };
+    if order != Ordering::Equal {
+      return order;
+    }
+    (a, b) = (a_rest, b_rest);
+  }
+}
+
+/// Whether the rest of a version marks a release candidate, as in `6.9.0-rc7`, which comes
+/// before the release itself.
+fn is_prerelease(rest: &str) -> bool {
+  rest.trim_start_matches(['-', '.', '_']).starts_with("rc")
+}
+
+/// Splits off a leading run of digits, or of anything else.
+fn split_version_part(version: &str) -> (&str, &str) {
+  let digits = version.starts_with(|c: char| c.is_ascii_digit());
+  let end = version
+    .find(|c: char| c.is_ascii_digit() != digits)
+    .unwrap_or(version.len());
+  version.split_at(end)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn compare_versions_orders_abi_numbers_numerically() {
+    assert_eq!(compare_versions("6.8.0-45", "6.8.0-9"), Ordering::Greater);
+    assert_eq!(compare_versions("6.8.0-9", "6.8.0-45"), Ordering::Less);
+    assert_eq!(compare_versions("6.10.0", "6.9.12"), Ordering::Greater);
+  }
+
+  #[test]
+  fn compare_versions_handles_flavour_suffixes() {
+    assert_eq!(
+      compare_versions("6.8.0-45-generic", "6.8.0-9-generic"),
+      Ordering::Greater
+    );
+    assert_eq!(
+      compare_versions("6.8.0-45-generic", "6.8.0-45-generic"),
+      Ordering::Equal
+    );
+    assert_eq!(
+      compare_versions("6.8.10-300.fc40.x86_64", "6.8.5-301.fc40.x86_64"),
+      Ordering::Greater
+    );
+    assert_eq!(
+      compare_versions("6.8.0-45-generic", "6.8.0-45"),
+      Ordering::Greater
+    );
+  }
+
+  #[test]
+  fn compare_versions_puts_release_candidates_first() {
+    assert_eq!(compare_versions("6.9.0-rc7", "6.9.0"), Ordering::Less);
+    assert_eq!(compare_versions("6.9.0", "6.9.0-rc7"), Ordering::Greater);
+    assert_eq!(compare_versions("6.9.0-rc7", "6.8.12"), Ordering::Greater);
+  }
+
+  #[test]
+  fn is_boot_file_of_matches_every_layout() {
+    let version = "6.8.0-45-generic";
+    for name in [
+      "vmlinuz-6.8.0-45-generic",
+      "initrd.img-6.8.0-45-generic",
+      "config-6.8.0-45-generic",
+      "System.map-6.8.0-45-generic",
+    ] {
+      assert!(is_boot_file_of(name, version), "{}", name);
+    }
+
+    let version = "6.8.5-301.fc40.x86_64";
+    assert!(is_boot_file_of(
+      "initramfs-6.8.5-301.fc40.x86_64.img",
+      version
+    ));
+    assert!(is_boot_file_of(
+      "initramfs-6.8.5-301.fc40.x86_64-kdump.img",
+      version
+    ));
+  }
+
+  #[test]
+  fn is_boot_file_of_rejects_other_versions() {
+    assert!(!is_boot_file_of("vmlinuz-6.8.0-45-generic", "6.8.0-4"));
+    assert!(!is_boot_file_of("vmlinuz-6.8.0-45-generic", "6.8.0-45"));
+    assert!(!is_boot_file_of(
+      "vmlinuz-16.8.0-45-generic",
+      "6.8.0-45-generic"
+    ));
+    assert!(!is_boot_file_of("vmlinuz", "6.8.0-45-generic"));
+  }
+
+  #[test]
+  fn boot_image_version_skips_rescue_images() {
+    assert_eq!(
+      boot_image_version("vmlinuz-6.8.0-45-generic"),
+      Some("6.8.0-45-generic")
+    );
+    assert_eq!(boot_image_version("vmlinuz-0-rescue-0123abcd"), None);
+    assert_eq!(boot_image_version("vmlinuz-linux"), None);
+    assert_eq!(boot_image_version("initrd.img-6.8.0-45-generic"), None);
+  }
+}
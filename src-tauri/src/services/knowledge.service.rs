@@ -0,0 +1,160 @@
+/* sys lib */
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/* services */
+use crate::services::systemd_service::SystemdService;
+
+/* models */
+use crate::models::{
+  AppError, DataValue, KnowledgeBaseModel, ResponseModel, ResponseStatus, ServiceKnowledgeModel,
+  ServiceRecommendationModel, ServiceRecommendationsModel, ServiceRisk, ServiceScope,
+};
+
+/* helpers */
+use serde_json::json;
+
+const BUNDLED_KNOWLEDGE: &str = include_str!("../../data/service-knowledge.json");
+
+pub struct KnowledgeService;
+
+#[allow(non_snake_case)]
+impl KnowledgeService {
+  pub fn getKnowledgeBase(&self) -> Result<ResponseModel, AppError> {
+    let knowledge = load_knowledge_base()?;
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Knowledge base covers {} units", knowledge.services.len()),
+      data: DataValue::Object(serde_json::to_value(knowledge).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn getServiceRecommendations(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    let knowledge = load_knowledge_base()?;
+    let services = SystemdService::connect(scope)?.listServices(&[])?;
+
+    let mut recommendations: Vec<ServiceRecommendationModel> = services
+      .into_iter()
+      .filter(|service| service.load == "loaded")
+      .filter_map(|service| {
+        let mut entry = find_entry(&knowledge, &service.name)?.clone();
+        if entry.deviceClass.as_deref().is_some_and(has_devices) {
+          entry.risk = entry.risk.max(ServiceRisk::Medium);
+        }
+        Some(ServiceRecommendationModel {
+          recommended: entry.risk <= ServiceRisk::Low && service.active == "active",
+          knowledge: entry,
+          unit: service,
+        })
+      })
+      .collect();
+    recommendations
+      .sort_by(|a, b| (a.knowledge.risk, &a.unit.name).cmp(&(b.knowledge.risk, &b.unit.name)));
+
+    // Presets only ever touch low-risk units that are actually present
+    let presets = knowledge
+      .presets
+      .into_iter()
+      .map(|mut preset| {
+        preset.units = recommendations
+          .iter()
+          .filter(|r| r.knowledge.risk <= ServiceRisk::Low)
+          .filter(|r| {
+            r.knowledge
+              .features
+              .iter()
+              .any(|f| preset.features.contains(f))
+          })
+          .map(|r| r.unit.name.clone())
+          .collect();
+        preset
+      })
+      .filter(|preset| !preset.units.is_empty())
+      .collect();
+
+    let result = ServiceRecommendationsModel {
+      recommendations,
+      presets,
+    };
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "{} of {} known services can be safely disabled",
+        result
+          .recommendations
+          .iter()
+          .filter(|r| r.recommended)
+          .count(),
+        result.recommendations.len()
+      ),
+      data: DataValue::Object(serde_json::to_value(result).unwrap_or(json!({}))),
+    })
+  }
+}
+
+/// The bundled knowledge base, with entries from the user's local copy added or replacing
+/// bundled ones of the same unit or preset id.
+pub fn load_knowledge_base() -> Result<KnowledgeBaseModel, AppError> {
+  let mut knowledge: KnowledgeBaseModel = serde_json::from_str(BUNDLED_KNOWLEDGE)
+    .map_err(|e| AppError::internal(format!("Bundled knowledge base is invalid: {}", e)))?;
+
+  let Some(path) = local_knowledge_path() else {
+    return Ok(knowledge);
+  };
+  let content = match fs::read_to_string(&path) {
+    Ok(content) => content,
+    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(knowledge),
+    Err(e) => {
+      return Err(AppError::io(
+        format!("Failed to read {}", path.display()),
+        &e,
+      ))
+    }
+  };
+  let local: KnowledgeBaseModel = serde_json::from_str(&content)
+    .map_err(|e| AppError::invalidInput(format!("Failed to parse {}: {}", path.display(), e)))?;
+
+  for entry in local.services {
+    knowledge.services.retain(|e| e.unit != entry.unit);
+    knowledge.services.push(entry);
+  }
+  for preset in local.presets {
+    knowledge.presets.retain(|p| p.id != preset.id);
+    knowledge.presets.push(preset);
+  }
+  knowledge.version = knowledge.version.max(local.version);
+  Ok(knowledge)
+}
+
+fn local_knowledge_path() -> Option<PathBuf> {
+  dirs::config_dir().map(|dir| dir.join("cleanux/service-knowledge.json"))
+}
+
+/// Whether `/sys/class/<class>` lists any device, e.g. a Bluetooth adapter as `hci0`.
+fn has_devices(class: &str) -> bool {
+  fs::read_dir(Path::new("/sys/class").join(class))
+    .map(|mut entries| entries.next().is_some())
+    .unwrap_or(false)
+}
+
+fn unit_matches(pattern: &str, unit: &str) -> bool {
+  match pattern.split_once("@.") {
+    Some((prefix, suffix)) => unit
+      .strip_prefix(prefix)
+      .and_then(|rest| rest.strip_prefix('@'))
+      .is_some_and(|rest| rest.ends_with(&format!(".{}", suffix))),
+    None => pattern == unit,
+  }
+}
+
+pub fn find_entry<'a>(
+  knowledge: &'a KnowledgeBaseModel,
+  unit: &str,
+) -> Option<&'a ServiceKnowledgeModel> {
+  knowledge
+    .services
+    .iter()
+    .find(|entry| unit_matches(&entry.unit, unit))
+}
@@ -0,0 +1,919 @@
+/* sys lib */
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+
+/* models */
+use crate::models::{
+  AppError, DataValue, ItemResultModel, LogAnalysisModel, LogFamilyModel, LogFileKind,
+  LogFileModel, LogLineModel, LogMessageCountModel, LogPageDirection, LogPageModel,
+  LogPageQueryModel, LogSeverity, LogSeverityCountsModel, LogSourceCountModel, ResponseModel,
+  ResponseStatus,
+};
+
+/* helpers */
+use rayon::prelude::*;
+use regex::{Regex, RegexBuilder};
+use serde_json::json;
+
+const DEFAULT_PAGE_LINES: usize = 200;
+const MAX_PAGE_LINES: usize = 5_000;
+/// Bytes scanned for one page before a search returns what it found so far.
+const MAX_SCAN_BYTES: u64 = 64 * 1024 * 1024;
+const CHUNK_BYTES: usize = 64 * 1024;
+/// Longer lines are cut to this length in pages.
+const MAX_LINE_BYTES: usize = 16 * 1024;
+const TOP_LIMIT: usize = 10;
+/// Distinct messages tallied per file, so a log full of unique lines cannot exhaust memory.
+const MAX_MESSAGES_PER_FILE: usize = 20_000;
+const MAX_MESSAGE_CHARS: usize = 200;
+/// Extensions of compressed or rotated copies, as written by logrotate and friends.
+const ROTATED_EXTENSIONS: [&str; 6] = ["gz", "xz", "bz2", "zst", "lz4", "old"];
+
+/// Login accounting databases, which `last`, `lastb` and `faillock` need intact.
+const ACCOUNTING_NAMES: [&str; 5] = ["wtmp", "btmp", "utmp", "lastlog", "faillog"];
+
+const MONTHS: [&str; 12] = [
+  "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Keywords checked in order, so "failed with fatal signal" counts as critical.
+const SEVERITY_KEYWORDS: [(LogSeverity, &[&str]); 4] = [
+  (
+    LogSeverity::Critical,
+    &[
+      "panic",
+      "fatal",
+      "critical",
+      "emerg",
+      "segfault",
+      "out of memory",
+      "oom-kill",
+    ],
+  ),
+  (
+    LogSeverity::Error,
+    &["error", "fail", "denied", "cannot", "can't", "unable to"],
+  ),
+  (
+    LogSeverity::Warning,
+    &["warn", "deprecated", "timed out", "timeout", "retrying"],
+  ),
+  (LogSeverity::Debug, &["debug", "trace"]),
+];
+
+/// A log opened for reading, decompressed on the fly by the matching tool when it is a
+/// compressed rotated copy.
+struct LogReader {
+  reader: Box<dyn Read>,
+  child: Option<Child>,
+}
+
+impl LogReader {
+  fn open(path: &Path) -> Result<Self, AppError> {
+    let file = File::open(path)
+      .map_err(|e| AppError::io(format!("Failed to open {}", path.display()), &e))?;
+    Self::decompress(file, path)
+  }
+
+  fn decompress(file: File, path: &Path) -> Result<Self, AppError> {
+    let Some(program) = decompressor(path) else {
+      return Ok(Self {
+        reader: Box::new(file),
+        child: None,
+      });
+    };
+    let mut child = Command::new(program)
+      .arg("-dc")
+      .stdin(file)
+      .stdout(Stdio::piped())
+      .stderr(Stdio::null())
+      .spawn()
+      .map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => AppError::notFound(format!(
+          "{} is needed to decompress {} but is not installed",
+          program,
+          path.display()
+        )),
+        _ => AppError::spawn(program, &e),
+      })?;
+    let stdout = child
+      .stdout
+      .take()
+      .ok_or_else(|| AppError::internal(format!("No output from {}", program)))?;
+    Ok(Self {
+      reader: Box::new(stdout),
+      child: Some(child),
+    })
+  }
+}
+
+impl Read for LogReader {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    self.reader.read(buf)
+  }
+}
+
+impl Drop for LogReader {
+  fn drop(&mut self) {
+    // Stops a decompressor whose output was only read in part
+    if let Some(child) = &mut self.child {
+      let _ = child.kill();
+      let _ = child.wait();
+    }
+  }
+}
+
+/// Lines collected for one page, with the bytes scanned to find them.
+struct PageScan<'a> {
+  pattern: Option<&'a Regex>,
+  limit: usize,
+  scanned: u64,
+  lines: VecDeque<LogLineModel>,
+}
+
+impl<'a> PageScan<'a> {
+  fn new(pattern: Option<&'a Regex>, limit: usize) -> Self {
+    Self {
+      pattern,
+      limit,
+      scanned: 0,
+      lines: VecDeque::new(),
+    }
+  }
+
+  /// Returns the line if it belongs on the page.
+  fn offer(&mut self, offset: u64, bytes: &[u8]) -> Option<LogLineModel> {
+    self.scanned += bytes.len() as u64;
+    let text = String::from_utf8_lossy(&bytes[..bytes.len().min(MAX_LINE_BYTES)]);
+    let text = text.trim_end_matches(['\n', '\r']);
+    if self.pattern.is_some_and(|pattern| !pattern.is_match(text)) {
+      return None;
+    }
+    Some(LogLineModel {
+      offset,
+      text: text.to_string(),
+    })
+  }
+
+  fn full(&self) -> bool {
+    self.lines.len() >= self.limit || self.scanned >= MAX_SCAN_BYTES
+  }
+}
+
+/// Byte range a page covers in the uncompressed log.
+struct ScanRange {
+  start: u64,
+  end: u64,
+  size: Option<u64>,
+  /// Whether lines remain past the page in the direction it was read.
+  more: bool,
+}
+
+pub struct LogService;
+
+#[allow(non_snake_case)]
+impl LogService {
+  /// Reads one page of a log without loading the rest: backward from the end by default,
+  /// or forward from an offset, optionally keeping only lines matching a regular
+  /// expression. Compressed rotated logs are decompressed as they are read.
+  pub fn getLogPage(&self, query: LogPageQueryModel) -> Result<ResponseModel, AppError> {
+    let path = Path::new(&query.path);
+    let metadata =
+      fs::metadata(path).map_err(|e| AppError::io(format!("Failed to read {}", query.path), &e))?;
+    if !metadata.is_file() {
+      return Err(AppError::invalidInput(format!(
+        "{} is not a file",
+        query.path
+      )));
+    }
+    let pattern = match query.pattern.as_deref().filter(|p| !p.is_empty()) {
+      Some(pattern) => Some(
+        RegexBuilder::new(pattern)
+          .case_insensitive(query.ignoreCase)
+          .build()
+          .map_err(|e| AppError::invalidInput(format!("Invalid search pattern: {}", e)))?,
+      ),
+      None => None,
+    };
+
+    let limit = query
+      .lines
+      .unwrap_or(DEFAULT_PAGE_LINES)
+      .clamp(1, MAX_PAGE_LINES);
+    let mut scan = PageScan::new(pattern.as_ref(), limit);
+    let compressed = decompressor(path).is_some();
+    let size = metadata.len();
+    let range = match (compressed, query.direction) {
+      (false, LogPageDirection::Backward) => {
+        let end = query.offset.unwrap_or(size).min(size);
+        read_backward(path, end, size, &mut scan)?
+      }
+      (false, LogPageDirection::Forward) => {
+        let from = query.offset.unwrap_or(0).min(size);
+        // Starting a byte early skips a partial first line unless the offset starts one
+        let base = from.saturating_sub(1);
+        let mut file = File::open(path)
+          .map_err(|e| AppError::io(format!("Failed to open {}", query.path), &e))?;
+        file
+          .seek(SeekFrom::Start(base))
+          .map_err(|e| AppError::io(format!("Failed to read {}", query.path), &e))?;
+        let range = read_forward(file, base, from, &mut scan);
+        ScanRange {
+          size: Some(size),
+          more: range.end < size,
+          ..range
+        }
+      }
+      (true, LogPageDirection::Forward) => read_forward(
+        LogReader::open(path)?,
+        0,
+        query.offset.unwrap_or(0),
+        &mut scan,
+      ),
+      (true, LogPageDirection::Backward) => {
+        read_tail(LogReader::open(path)?, query.offset, &mut scan)
+      }
+    };
+
+    let hasBefore = range.start > 0;
+    let hasAfter = match query.direction {
+      LogPageDirection::Backward => range.size.is_none_or(|size| range.end < size),
+      LogPageDirection::Forward => range.more,
+    };
+    let scanLimited = scan.lines.len() < limit
+      && match query.direction {
+        LogPageDirection::Backward => hasBefore,
+        LogPageDirection::Forward => hasAfter,
+      };
+    let page = LogPageModel {
+      path: query.path.clone(),
+      compressed,
+      size: range.size,
+      start: range.start,
+      end: range.end,
+      hasBefore,
+      hasAfter,
+      scanLimited,
+      lines: scan.lines.into(),
+    };
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: match pattern {
+        Some(_) => format!(
+          "Found {} matching lines in {}",
+          page.lines.len(),
+          query.path
+        ),
+        None => format!("Read {} lines from {}", page.lines.len(), query.path),
+      },
+      data: DataValue::Object(serde_json::to_value(page).unwrap_or(json!({}))),
+    })
+  }
+}
+
+/// Tallies of one file, merged into the overall analysis once every file is read.
+#[derive(Default)]
+struct LogTally {
+  analyzed: usize,
+  skipped: usize,
+  failed: Vec<ItemResultModel>,
+  truncated: usize,
+  lines: u64,
+  bytes: u64,
+  severities: LogSeverityCountsModel,
+  messages: HashMap<(String, String), (LogSeverity, u64)>,
+  sources: HashMap<String, LogSourceCountModel>,
+}
+
+impl LogTally {
+  fn skipped() -> Self {
+    Self {
+      skipped: 1,
+      ..Self::default()
+    }
+  }
+
+  fn failed(path: &Path, error: AppError) -> Self {
+    Self {
+      failed: vec![ItemResultModel::failed(path.to_string_lossy(), error)],
+      ..Self::default()
+    }
+  }
+
+  fn add(&mut self, source: &str, message: &str, bytes: u64) {
+    let severity = severity_of(message);
+    self.lines += 1;
+    self.bytes += bytes;
+    let counter = match severity {
+      LogSeverity::Critical => &mut self.severities.critical,
+      LogSeverity::Error => &mut self.severities.error,
+      LogSeverity::Warning => &mut self.severities.warning,
+      LogSeverity::Info => &mut self.severities.info,
+      LogSeverity::Debug => &mut self.severities.debug,
+    };
+    *counter += 1;
+
+    let entry = self
+      .sources
+      .entry(source.to_string())
+      .or_insert_with(|| LogSourceCountModel {
+        source: source.to_string(),
+        lines: 0,
+        bytes: 0,
+        errors: 0,
+      });
+    entry.lines += 1;
+    entry.bytes += bytes;
+    if matches!(severity, LogSeverity::Critical | LogSeverity::Error) {
+      entry.errors += 1;
+    }
+
+    let key = (source.to_string(), normalize_message(message));
+    if let Some((_, count)) = self.messages.get_mut(&key) {
+      *count += 1;
+    } else if self.messages.len() < MAX_MESSAGES_PER_FILE {
+      self.messages.insert(key, (severity, 1));
+    }
+  }
+
+  fn merge(mut self, other: Self) -> Self {
+    self.analyzed += other.analyzed;
+    self.skipped += other.skipped;
+    self.failed.extend(other.failed);
+    self.truncated += other.truncated;
+    self.lines += other.lines;
+    self.bytes += other.bytes;
+    self.severities.critical += other.severities.critical;
+    self.severities.error += other.severities.error;
+    self.severities.warning += other.severities.warning;
+    self.severities.info += other.severities.info;
+    self.severities.debug += other.severities.debug;
+    for (key, (severity, count)) in other.messages {
+      self.messages.entry(key).or_insert((severity, 0)).1 += count;
+    }
+    for (source, counts) in other.sources {
+      let entry = self
+        .sources
+        .entry(source)
+        .or_insert_with(|| LogSourceCountModel {
+          source: counts.source.clone(),
+          lines: 0,
+          bytes: 0,
+          errors: 0,
+        });
+      entry.lines += counts.lines;
+      entry.bytes += counts.bytes;
+      entry.errors += counts.errors;
+    }
+    self
+  }
+
+  fn into_model(self) -> LogAnalysisModel {
+    let mut top_messages: Vec<LogMessageCountModel> = self
+      .messages
+      .into_iter()
+      .map(
+        |((source, message), (severity, count))| LogMessageCountModel {
+          source,
+          message,
+          severity,
+          count,
+        },
+      )
+      .collect();
+    top_messages.sort_by(|a, b| {
+      b.count
+        .cmp(&a.count)
+        .then_with(|| a.message.cmp(&b.message))
+    });
+    top_messages.truncate(TOP_LIMIT);
+
+    let mut top_sources: Vec<LogSourceCountModel> = self.sources.into_values().collect();
+    top_sources.sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| a.source.cmp(&b.source)));
+    top_sources.truncate(TOP_LIMIT);
+
+    LogAnalysisModel {
+      analyzedFiles: self.analyzed,
+      skippedFiles: self.skipped,
+      failedFiles: self.failed,
+      truncatedFiles: self.truncated,
+      lines: self.lines,
+      bytes: self.bytes,
+      severities: self.severities,
+      topMessages: top_messages,
+      topSources: top_sources,
+    }
+  }
+}
+
+/// Reads the contents of active and rotated logs, decompressing rotated copies, and
+/// counts lines by severity, repeated messages and the programs writing them. At most
+/// `max_bytes` of each log are read.
+pub fn analyze_logs(files: &[LogFileModel], max_bytes: u64) -> LogAnalysisModel {
+  files
+    .par_iter()
+    .filter(|file| matches!(file.kind, LogFileKind::Active | LogFileKind::Rotated))
+    .map(|file| analyze_file(Path::new(&file.path), max_bytes))
+    .reduce(LogTally::default, LogTally::merge)
+    .into_model()
+}
+
+/// Groups active and rotated logs by the log they were rotated from, largest family first.
+pub fn group_log_families(files: Vec<LogFileModel>) -> Vec<LogFamilyModel> {
+  let mut families: HashMap<PathBuf, LogFamilyModel> = HashMap::new();
+  for file in files {
+    let path = Path::new(&file.path);
+    let base = match file.kind {
+      LogFileKind::Active => path.to_path_buf(),
+      LogFileKind::Rotated => match rotated_log_base(path) {
+        Some(base) => path.with_file_name(base),
+        None => continue,
+      },
+      LogFileKind::Accounting | LogFileKind::Journal => continue,
+    };
+    let family = families
+      .entry(base)
+      .or_insert_with_key(|base| LogFamilyModel {
+        base: base.to_string_lossy().to_string(),
+        active: None,
+        rotations: Vec::new(),
+        totalSize: 0,
+        rotatedSize: 0,
+      });
+    family.totalSize += file.size;
+    if file.kind == LogFileKind::Rotated {
+      family.rotatedSize += file.size;
+      family.rotations.push(file);
+    } else {
+      family.active = Some(file);
+    }
+  }
+
+  let mut families: Vec<LogFamilyModel> = families
+    .into_values()
+    .map(|mut family| {
+      family.rotations.sort_by(|a, b| {
+        b.modified
+          .cmp(&a.modified)
+          .then_with(|| a.path.cmp(&b.path))
+      });
+      family
+    })
+    .collect();
+  families.sort_by(|a, b| {
+    b.totalSize
+      .cmp(&a.totalSize)
+      .then_with(|| a.base.cmp(&b.base))
+  });
+  families
+}
+
+pub fn log_file_kind(path: &Path) -> LogFileKind {
+  let name = path
+    .file_name()
+    .and_then(|n| n.to_str())
+    .unwrap_or_default();
+  if ACCOUNTING_NAMES
+    .iter()
+    .any(|accounting| name.starts_with(accounting))
+  {
+    return LogFileKind::Accounting;
+  }
+  if name.ends_with(".journal") || name.ends_with(".journal~") {
+    return LogFileKind::Journal;
+  }
+  if rotated_log_base(path).is_some() {
+    return LogFileKind::Rotated;
+  }
+  LogFileKind::Active
+}
+
+/// Name of the log a rotated copy was rotated from, or `None` for a log that is still
+/// being written: `syslog.1`, `syslog.2.gz` and `syslog-20240101` all come from `syslog`.
+pub fn rotated_log_base(path: &Path) -> Option<String> {
+  let name = path.file_name()?.to_str()?;
+
+  let mut stem = name;
+  if let Some((rest, ext)) = stem.rsplit_once('.') {
+    if ROTATED_EXTENSIONS.contains(&ext) {
+      if ext == "old" {
+        return (!rest.is_empty()).then(|| rest.to_string());
+      }
+      stem = rest;
+    }
+  }
+
+  if let Some((rest, ext)) = stem.rsplit_once('.') {
+    if !rest.is_empty() && !ext.is_empty() && ext.chars().all(|c| c.is_ascii_digit()) {
+      return Some(rest.to_string());
+    }
+  }
+  if let Some((rest, dated)) = stem.rsplit_once('-') {
+    let (date, suffix) = dated.split_at(dated.find('.').unwrap_or(dated.len()));
+    if !rest.is_empty() && date.len() == 8 && date.chars().all(|c| c.is_ascii_digit()) {
+      return Some(format!("{}{}", rest, suffix));
+    }
+  }
+  // A compressed file without a rotation number is still an archived copy
+  (stem != name).then(|| stem.to_string())
+}
+
+fn analyze_file(path: &Path, max_bytes: u64) -> LogTally {
+  // Logs only root may read are expected and just counted, unlike a missing decompressor
+  let Ok(file) = File::open(path) else {
+    return LogTally::skipped();
+  };
+  let reader = match LogReader::decompress(file, path) {
+    Ok(reader) => reader,
+    Err(error) => return LogTally::failed(path, error),
+  };
+  let source = rotated_log_base(path)
+    .or_else(|| {
+      path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+    })
+    .unwrap_or_default();
+  let mut reader = BufReader::new(reader.take(max_bytes));
+  let mut tally = read_lines(&mut reader, &source);
+  // Anything left past the limit means only part of the log was analyzed
+  let mut rest = [0u8; 1];
+  if tally.analyzed > 0
+    && reader
+      .into_inner()
+      .into_inner()
+      .read(&mut rest)
+      .is_ok_and(|n| n > 0)
+  {
+    tally.truncated = 1;
+  }
+  tally
+}
+
+/// Reads a plain log backward in chunks from `end`, handing lines to the scan from the
+/// last one up until the page is full.
+fn read_backward(
+  path: &Path,
+  end: u64,
+  size: u64,
+  scan: &mut PageScan,
+) -> Result<ScanRange, AppError> {
+  let mut file =
+    File::open(path).map_err(|e| AppError::io(format!("Failed to open {}", path.display()), &e))?;
+  // `tail` holds the bytes from `pos` up to `cursor`, where the last scanned line starts
+  let mut pos = end;
+  let mut cursor = end;
+  let mut tail: Vec<u8> = Vec::new();
+  while cursor > 0 && !scan.full() {
+    let body = &tail[..tail.len().saturating_sub(1)];
+    if let Some(index) = body.iter().rposition(|&b| b == b'\n') {
+      let line = tail.split_off(index + 1);
+      cursor = pos + index as u64 + 1;
+      if let Some(line) = scan.offer(cursor, &line) {
+        scan.lines.push_front(line);
+      }
+      continue;
+    }
+    if pos == 0 {
+      cursor = 0;
+      if let Some(line) = scan.offer(0, &tail) {
+        scan.lines.push_front(line);
+      }
+      break;
+    }
+    if tail.len() as u64 >= MAX_SCAN_BYTES {
+      break;
+    }
+
+    let chunk_start = pos.saturating_sub(CHUNK_BYTES as u64);
+    let mut chunk = vec![0u8; (pos - chunk_start) as usize];
+    file
+      .seek(SeekFrom::Start(chunk_start))
+      .and_then(|_| file.read_exact(&mut chunk))
+      .map_err(|e| AppError::io(format!("Failed to read {}", path.display()), &e))?;
+    chunk.extend_from_slice(&tail);
+    tail = chunk;
+    pos = chunk_start;
+  }
+
+  Ok(ScanRange {
+    start: cursor,
+    end,
+    size: Some(size),
+    more: cursor > 0,
+  })
+}
+
+/// Reads lines forward from `from`, skipping any that start before it. `base` is the
+/// offset the reader starts at.
+fn read_forward(reader: impl Read, base: u64, from: u64, scan: &mut PageScan) -> ScanRange {
+  let mut reader = BufReader::with_capacity(CHUNK_BYTES, reader);
+  let mut offset = base;
+  let mut end = from;
+  let mut buffer = Vec::new();
+  loop {
+    if scan.full() {
+      let more = reader.fill_buf().is_ok_and(|rest| !rest.is_empty());
+      return ScanRange {
+        start: from,
+        end,
+        size: None,
+        more,
+      };
+    }
+    buffer.clear();
+    let read = match reader.read_until(b'\n', &mut buffer) {
+      Ok(0) | Err(_) => break,
+      Ok(read) => read as u64,
+    };
+    let line_start = offset;
+    offset += read;
+    if line_start < from {
+      continue;
+    }
+    end = offset;
+    if let Some(line) = scan.offer(line_start, &buffer) {
+      scan.lines.push_back(line);
+    }
+  }
+
+  ScanRange {
+    start: from.min(offset),
+    end: offset,
+    size: Some(offset),
+    more: false,
+  }
+}
+
+/// Reads a compressed log from the start up to `end`, keeping the last page of lines,
+/// since a compressed stream cannot be read backward.
+fn read_tail(reader: impl Read, end: Option<u64>, scan: &mut PageScan) -> ScanRange {
+  let mut reader = BufReader::with_capacity(CHUNK_BYTES, reader);
+  let mut offset = 0;
+  let mut buffer = Vec::new();
+  let mut size = None;
+  loop {
+    if end.is_some_and(|end| offset >= end) {
+      break;
+    }
+    buffer.clear();
+    let read = match reader.read_until(b'\n', &mut buffer) {
+      Ok(0) | Err(_) => {
+        size = Some(offset);
+        break;
+      }
+      Ok(read) => read as u64,
+    };
+    if let Some(line) = scan.offer(offset, &buffer) {
+      scan.lines.push_back(line);
+      if scan.lines.len() > scan.limit {
+        scan.lines.pop_front();
+      }
+    }
+    offset += read;
+  }
+
+  let start = match scan.lines.front() {
+    Some(first) if scan.lines.len() >= scan.limit => first.offset,
+    _ => 0,
+  };
+  ScanRange {
+    start,
+    end: offset,
+    size,
+    more: start > 0,
+  }
+}
+
+fn read_lines(reader: &mut impl BufRead, fallback: &str) -> LogTally {
+  // Binary files such as databases show NUL bytes right away
+  if reader.fill_buf().map_or(true, |head| head.contains(&0)) {
+    return LogTally::skipped();
+  }
+
+  let mut tally = LogTally {
+    analyzed: 1,
+    ..LogTally::default()
+  };
+  let mut buffer = Vec::new();
+  loop {
+    buffer.clear();
+    match reader.read_until(b'\n', &mut buffer) {
+      Ok(0) | Err(_) => break,
+      // A line without its newline was cut off by the byte limit or is still being written
+      Ok(_) if buffer.last() != Some(&b'\n') => break,
+      Ok(read) => {
+        let line = String::from_utf8_lossy(&buffer);
+        let line = line.trim_end_matches(['\n', '\r', '\0']);
+        if line.trim().is_empty() {
+          continue;
+        }
+        let (source, message) = parse_syslog(line).unwrap_or((fallback, line));
+        tally.add(source, message, read as u64);
+      }
+    }
+  }
+  tally
+}
+
+fn decompressor(path: &Path) -> Option<&'static str> {
+  match path.extension()?.to_str()? {
+    "gz" => Some("gzip"),
+    "xz" => Some("xz"),
+    "bz2" => Some("bzip2"),
+    "zst" => Some("zstd"),
+    "lz4" => Some("lz4"),
+    _ => None,
+  }
+}
+
+/// Splits a syslog line into the program and the message. Both the traditional
+/// `Jan  2 03:04:05 host prog[pid]: msg` and the RFC 3339 timestamp form are understood.
+fn parse_syslog(line: &str) -> Option<(&str, &str)> {
+  let first = line.split_whitespace().next()?;
+  let rest = if MONTHS.contains(&first) {
+    let mut fields = line.split_whitespace().skip(1);
+    let day = fields.next()?;
+    let time = fields.next()?;
+    if !day.chars().all(|c| c.is_ascii_digit()) || time.matches(':').count() != 2 {
+      return None;
+    }
+    skip_fields(line, 4)?
+  } else if first.len() >= 19 && first.as_bytes()[4] == b'-' && first.as_bytes()[10] == b'T' {
+    skip_fields(line, 2)?
+  } else {
+    return None;
+  };
+
+  let (tag, message) = rest.split_once(": ")?;
+  let program = tag.split('[').next().unwrap_or(tag);
+  if program.is_empty() || program.contains(char::is_whitespace) {
+    return None;
+  }
+  Some((program, message))
+}
+
+/// Remainder of `line` after its first `count` whitespace separated fields.
+fn skip_fields(line: &str, count: usize) -> Option<&str> {
+  let mut rest = line;
+  for _ in 0..count {
+    rest = rest.trim_start();
+    let end = rest.find(char::is_whitespace)?;
+    rest = &rest[end..];
+  }
+  Some(rest.trim_start())
+}
+
+fn severity_of(message: &str) -> LogSeverity {
+  let message = message.to_lowercase();
+  SEVERITY_KEYWORDS
+    .iter()
+    .find(|(_, keywords)| keywords.iter().any(|keyword| message.contains(keyword)))
+    .map(|(severity, _)| *severity)
+    .unwrap_or(LogSeverity::Info)
+}
+
+fn normalize_message(message: &str) -> String {
+  let mut normalized = String::new();
+  let mut in_digits = false;
+  for c in message.chars().take(MAX_MESSAGE_CHARS) {
+    if c.is_ascii_digit() {
+      if !in_digits {
+        normalized.push('#');
+      }
+      in_digits = true;
+    } else {
+      normalized.push(c);
+      in_digits = false;
+    }
+  }
+  normalized
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Cursor;
+
+  fn temp_log(name: &str, contents: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("cleanux-{}-{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+  }
+
+  fn page_backward(path: &Path, end: u64, limit: usize) -> (ScanRange, Vec<LogLineModel>) {
+    let size = fs::metadata(path).unwrap().len();
+    let mut scan = PageScan::new(None, limit);
+    let range = read_backward(path, end, size, &mut scan).unwrap();
+    (range, scan.lines.into())
+  }
+
+  #[test]
+  fn parses_traditional_syslog_lines() {
+    assert_eq!(
+      parse_syslog("Jan  2 03:04:05 host sshd[123]: Accepted publickey"),
+      Some(("sshd", "Accepted publickey"))
+    );
+  }
+
+  #[test]
+  fn parses_rfc3339_syslog_lines() {
+    assert_eq!(
+      parse_syslog("2024-01-02T03:04:05.123456+01:00 host kernel: usb 1-1: reset"),
+      Some(("kernel", "usb 1-1: reset"))
+    );
+  }
+
+  #[test]
+  fn leaves_other_lines_unparsed() {
+    assert_eq!(parse_syslog("plain text: with a colon"), None);
+    assert_eq!(parse_syslog("Jan 2 soon host prog: message"), None);
+    assert_eq!(parse_syslog(""), None);
+  }
+
+  #[test]
+  fn normalizes_runs_of_digits() {
+    assert_eq!(
+      normalize_message("pid 1234 exited after 56ms"),
+      "pid # exited after #ms"
+    );
+    assert_eq!(normalize_message("0x7f3a"), "#x#f#a");
+  }
+
+  #[test]
+  fn counts_crlf_lines_and_drops_an_unterminated_last_line() {
+    let mut reader =
+      Cursor::new(b"Jan  2 03:04:05 host cron[1]: started\r\nplain\n\npartial".to_vec());
+    let tally = read_lines(&mut reader, "syslog");
+
+    assert_eq!(tally.analyzed, 1);
+    assert_eq!(tally.lines, 2);
+    assert!(tally.sources.contains_key("cron"));
+    assert!(tally.sources.contains_key("syslog"));
+  }
+
+  #[test]
+  fn skips_binary_files() {
+    let mut reader = Cursor::new(b"\0\0\0\x01utmp".to_vec());
+    let tally = read_lines(&mut reader, "wtmp");
+
+    assert_eq!((tally.analyzed, tally.skipped), (0, 1));
+  }
+
+  #[test]
+  fn pages_backward_over_crlf_and_an_unterminated_last_line() {
+    let path = temp_log("crlf.log", b"one\ntwo\r\nthree");
+    let (range, lines) = page_backward(&path, 14, 2);
+    let texts: Vec<&str> = lines.iter().map(|l| l.text.as_str()).collect();
+    assert_eq!(texts, ["two", "three"]);
+    assert_eq!(lines[0].offset, 4);
+    assert_eq!((range.start, range.end, range.more), (4, 14, true));
+
+    let (range, lines) = page_backward(&path, range.start, 2);
+    assert_eq!(lines.len(), 1);
+    assert_eq!((lines[0].offset, lines[0].text.as_str()), (0, "one"));
+    assert_eq!((range.start, range.more), (0, false));
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn pages_backward_across_chunk_boundaries() {
+    let contents: String = (0..20_000).map(|n| format!("line {}\n", n)).collect();
+    let path = temp_log("chunks.log", contents.as_bytes());
+
+    // Paging back to the start must return every line once, in order, at its offset
+    let mut pages = Vec::new();
+    let mut end = contents.len() as u64;
+    while end > 0 {
+      let (range, lines) = page_backward(&path, end, 997);
+      pages.push(lines);
+      end = range.start;
+    }
+    let lines: Vec<LogLineModel> = pages.into_iter().rev().flatten().collect();
+    assert_eq!(lines.len(), 20_000);
+    for (n, line) in lines.iter().enumerate() {
+      assert_eq!(line.text, format!("line {}", n));
+      assert!(contents[line.offset as usize..].starts_with(&line.text));
+    }
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn pages_backward_over_lines_longer_than_a_chunk() {
+    let long = "x".repeat(CHUNK_BYTES * 2);
+    let contents = format!("first\n{}\nlast\n", long);
+    let path = temp_log("long.log", contents.as_bytes());
+    let (range, lines) = page_backward(&path, contents.len() as u64, 3);
+
+    let offsets: Vec<u64> = lines.iter().map(|l| l.offset).collect();
+    assert_eq!(offsets, [0, 6, 7 + long.len() as u64]);
+    assert_eq!(lines[1].text.len(), MAX_LINE_BYTES);
+    assert_eq!(lines[2].text, "last");
+    assert_eq!(range.start, 0);
+    fs::remove_file(path).unwrap();
+  }
+}
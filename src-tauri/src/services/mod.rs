@@ -8,3 +8,48 @@ pub mod dashboard_service;
 
 #[path = "system.service.rs"]
 pub mod system_service;
+
+#[path = "schedule.service.rs"]
+pub mod schedule_service;
+
+#[path = "monitor.service.rs"]
+pub mod monitor_service;
+
+#[path = "settings.service.rs"]
+pub mod settings_service;
+
+#[path = "profile.service.rs"]
+pub mod profile_service;
+
+#[path = "systemd.service.rs"]
+pub mod systemd_service;
+
+#[path = "journal.service.rs"]
+pub mod journal_service;
+
+#[path = "boot.service.rs"]
+pub mod boot_service;
+
+#[path = "knowledge.service.rs"]
+pub mod knowledge_service;
+
+#[path = "snapshot.service.rs"]
+pub mod snapshot_service;
+
+#[path = "helper.service.rs"]
+pub mod helper_service;
+
+#[path = "privileged.service.rs"]
+pub mod privileged_service;
+
+#[path = "elevation.service.rs"]
+pub mod elevation_service;
+
+#[path = "log.service.rs"]
+pub mod log_service;
+
+#[path = "package.service.rs"]
+pub mod package_service;
+
+#[path = "kernel.service.rs"]
+pub mod kernel_service;
@@ -0,0 +1,364 @@
+/* sys lib */
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/* services */
+use crate::services::dashboard_service::DashboardService;
+use crate::services::profile_service::ProfileService;
+use crate::services::schedule_service::{cleanux_exe, systemctl_user, unit_escape, user_unit_dir};
+use crate::services::settings_service::{SettingsService, SettingsState};
+
+/* models */
+use crate::models::{
+  AppError, DataValue, DiskUsageModel, MonitorConfigModel, ResponseModel, ResponseStatus,
+  ScanSummaryModel,
+};
+
+/* helpers */
+use rustix::fs::{flock, FlockOperation};
+use serde_json::json;
+use zbus::blocking::{Connection, Proxy};
+use zbus::zvariant::Value;
+
+const RUN_CLEANUP_ACTION: &str = "run-cleanup";
+const MONITOR_UNIT: &str = "cleanux-monitor.service";
+
+pub struct MonitorService {
+  settings: SettingsState,
+}
+
+#[allow(non_snake_case)]
+impl MonitorService {
+  pub fn new(settings: SettingsState) -> Self {
+    Self { settings }
+  }
+
+  pub fn getDiskUsage(&self) -> Result<ResponseModel, AppError> {
+    let usage: Vec<DiskUsageModel> = self
+      .watchedPaths()
+      .iter()
+      .filter_map(|path| disk_usage(path))
+      .collect();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: "Disk usage retrieved successfully".to_string(),
+      data: DataValue::Array(
+        usage
+          .into_iter()
+          .map(|u| serde_json::to_value(u).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
+  }
+
+  pub fn spawnMonitor(self) {
+    thread::spawn(move || self.monitor(false));
+  }
+
+  /// Runs the monitor in its own process, as the user service does. Settings are read
+  /// again every round, since the app changes them from another process.
+  pub fn runMonitor(&self) {
+    self.monitor(true);
+  }
+
+  /// Installs and starts the user service that runs `cleanux --monitor` while the monitor
+  /// is enabled, so disks are watched with the app closed, and removes it otherwise.
+  pub fn syncMonitorUnit(&self) -> Result<(), AppError> {
+    let unitDir = user_unit_dir()?;
+    let unitPath = unitDir.join(MONITOR_UNIT);
+    if !self.settings.get().monitor.enabled {
+      if !unitPath.exists() {
+        return Ok(());
+      }
+      systemctl_user(&["disable", "--now", MONITOR_UNIT])?;
+      fs::remove_file(&unitPath)
+        .map_err(|e| AppError::io(format!("Failed to remove {}", unitPath.display()), &e))?;
+      return systemctl_user(&["daemon-reload"]);
+    }
+
+    let unit = format!(
+      "[Unit]\n\
+       Description=Cleanux free disk space monitor\n\
+       \n\
+       [Service]\n\
+       ExecStart=\"{exe}\" --monitor\n\
+       Restart=on-failure\n\
+       \n\
+       [Install]\n\
+       WantedBy=default.target\n",
+      exe = unit_escape(&cleanux_exe()?.display().to_string()),
+    );
+    if fs::read_to_string(&unitPath).ok().as_deref() != Some(unit.as_str()) {
+      fs::create_dir_all(&unitDir)
+        .map_err(|e| AppError::io(format!("Failed to create {}", unitDir.display()), &e))?;
+      fs::write(&unitPath, unit)
+        .map_err(|e| AppError::io(format!("Failed to write {}", unitPath.display()), &e))?;
+      systemctl_user(&["daemon-reload"])?;
+    }
+    systemctl_user(&["enable", "--now", MONITOR_UNIT])
+  }
+
+  fn monitor(&self, reload: bool) {
+    let pending: Arc<Mutex<HashMap<u32, String>>> = Arc::new(Mutex::new(HashMap::new()));
+    let mut alerted: HashSet<PathBuf> = HashSet::new();
+    let mut connection: Option<Connection> = None;
+    let mut lock = None;
+
+    loop {
+      if reload {
+        if let Ok(settings) = SettingsService.loadSettings() {
+          self.settings.set(settings);
+        }
+      }
+      let config = self.settings.get().monitor;
+      if !config.enabled {
+        lock = None;
+        alerted.clear();
+        thread::sleep(Duration::from_secs(60));
+        continue;
+      }
+
+      // The app and the user service both run a monitor; only the one holding the lock
+      // watches, and the other takes over if it goes away
+      if lock.is_none() {
+        lock = monitor_lock();
+        if lock.is_none() {
+          thread::sleep(Duration::from_secs(60));
+          continue;
+        }
+      }
+
+      // Some notification servers send ActionInvoked only to the connection that called
+      // Notify, so notifications are sent and listened for on one connection kept open
+      if connection.is_none() {
+        connection = Connection::session()
+          .and_then(|connection| {
+            spawn_action_listener(&connection, self.settings.clone(), pending.clone())?;
+            Ok(connection)
+          })
+          .map_err(|e| eprintln!("Failed to connect to the session bus: {}", e))
+          .ok();
+      }
+      let Some(connection) = &connection else {
+        thread::sleep(Duration::from_secs(60));
+        continue;
+      };
+
+      for usage in self.watchedPaths().iter().filter_map(|p| disk_usage(p)) {
+        let path = PathBuf::from(&usage.path);
+        if usage.freePercent >= f64::from(config.minFreePercent) {
+          alerted.remove(&path);
+          continue;
+        }
+        if !alerted.insert(path) {
+          continue;
+        }
+
+        match self.sendLowSpaceNotification(connection, &usage, &config) {
+          Ok(id) => {
+            if let Some(profileId) = &config.profileId {
+              if let Ok(mut pending) = pending.lock() {
+                pending.insert(id, profileId.clone());
+              }
+            }
+          }
+          Err(e) => eprintln!("Failed to send low disk space notification: {}", e),
+        }
+      }
+
+      thread::sleep(Duration::from_secs(u64::from(config.intervalMinutes) * 60));
+    }
+  }
+
+  fn sendLowSpaceNotification(
+    &self,
+    connection: &Connection,
+    usage: &DiskUsageModel,
+    config: &MonitorConfigModel,
+  ) -> zbus::Result<u32> {
+    let dashboardService = DashboardService::new(self.settings.get());
+    let summaries = [
+      ("Cache", dashboardService.getCacheSummary()),
+      ("Trash", dashboardService.getTrashSummary()),
+      ("Logs", dashboardService.getLogSummary()),
+    ];
+    let reclaimable: Vec<String> = summaries
+      .into_iter()
+      .filter_map(|(label, response)| {
+        let DataValue::Object(value) = response.ok()?.data else {
+          return None;
+        };
+        let summary: ScanSummaryModel = serde_json::from_value(value).ok()?;
+        Some(format!("{}: {}", label, format_size(summary.totalSize)))
+      })
+      .collect();
+
+    let summary = format!("Low disk space on {}", usage.path);
+    let body = format!(
+      "Only {} ({:.0}%) free.\n{}",
+      format_size(usage.freeBytes),
+      usage.freePercent,
+      reclaimable.join(", ")
+    );
+    let actions: Vec<&str> = match &config.profileId {
+      Some(_) => vec![RUN_CLEANUP_ACTION, "Run cleanup"],
+      None => vec![],
+    };
+
+    notify(connection, &summary, &body, &actions)
+  }
+
+  fn watchedPaths(&self) -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from("/")];
+    if let Some(home) = dirs::home_dir() {
+      let sameDevice = match (fs::metadata("/"), fs::metadata(&home)) {
+        (Ok(root), Ok(homeMeta)) => root.dev() == homeMeta.dev(),
+        _ => false,
+      };
+      if !sameDevice {
+        paths.push(home);
+      }
+    }
+    paths
+  }
+}
+
+fn disk_usage(path: &Path) -> Option<DiskUsageModel> {
+  let stat = rustix::fs::statvfs(path).ok()?;
+  let total_bytes = stat.f_blocks * stat.f_frsize;
+  let free_bytes = stat.f_bavail * stat.f_frsize;
+  if total_bytes == 0 {
+    return None;
+  }
+
+  Some(DiskUsageModel {
+    path: path.to_string_lossy().to_string(),
+    totalBytes: total_bytes,
+    freeBytes: free_bytes,
+    freePercent: free_bytes as f64 * 100.0 / total_bytes as f64,
+  })
+}
+
+/// Takes the lock that keeps two monitors from sending the same notifications. It is held
+/// until the returned file is closed.
+fn monitor_lock() -> Option<fs::File> {
+  let dir = dirs::runtime_dir().or_else(dirs::cache_dir)?;
+  let file = fs::OpenOptions::new()
+    .create(true)
+    .truncate(false)
+    .write(true)
+    .open(dir.join("cleanux-monitor.lock"))
+    .ok()?;
+  flock(&file, FlockOperation::NonBlockingLockExclusive).ok()?;
+  Some(file)
+}
+
+fn notification_proxy(connection: &Connection) -> zbus::Result<Proxy<'static>> {
+  Proxy::new(
+    connection,
+    "org.freedesktop.Notifications",
+    "/org/freedesktop/Notifications",
+    "org.freedesktop.Notifications",
+  )
+}
+
+fn notify(
+  connection: &Connection,
+  summary: &str,
+  body: &str,
+  actions: &[&str],
+) -> zbus::Result<u32> {
+  let proxy = notification_proxy(connection)?;
+  let hints: HashMap<&str, Value> = HashMap::new();
+  proxy.call(
+    "Notify",
+    &(
+      "Cleanux",
+      0u32,
+      "com.tcs.cleanux",
+      summary,
+      body,
+      actions,
+      hints,
+      -1i32,
+    ),
+  )
+}
+
+/// Listens on the connection notifications are sent from and runs the profile when a
+/// pending notification's action is clicked.
+fn spawn_action_listener(
+  connection: &Connection,
+  settings: SettingsState,
+  pending: Arc<Mutex<HashMap<u32, String>>>,
+) -> zbus::Result<()> {
+  let proxy = notification_proxy(connection)?;
+  let signals = proxy.receive_all_signals()?;
+  let connection = connection.clone();
+
+  thread::spawn(move || {
+    for message in signals {
+      let header = message.header();
+      let member = header.member().map(|member| member.as_str());
+      // A notification closed without its action being clicked leaves nothing to run
+      if member == Some("NotificationClosed") {
+        if let Ok((id, _)) = message.body().deserialize::<(u32, u32)>() {
+          if let Ok(mut pending) = pending.lock() {
+            pending.remove(&id);
+          }
+        }
+        continue;
+      }
+      if member != Some("ActionInvoked") {
+        continue;
+      }
+      let Ok((id, action)) = message.body().deserialize::<(u32, String)>() else {
+        continue;
+      };
+      let profile_id = match pending.lock() {
+        Ok(mut pending) => pending.remove(&id),
+        Err(_) => None,
+      };
+      let Some(profile_id) = profile_id.filter(|_| action == RUN_CLEANUP_ACTION) else {
+        continue;
+      };
+
+      // Clicking the action is the confirmation, so the profile runs without a preview
+      let (summary, body) =
+        match ProfileService::new(settings.clone()).executeProfile(&profile_id, false) {
+          Ok(run) => (
+            "Cleanup finished",
+            format!(
+              "Removed {} of {} files, freed {}",
+              run.removedFiles,
+              run.totalFiles,
+              format_size(run.freedBytes)
+            ),
+          ),
+          Err(error) => ("Cleanup failed", error.message),
+        };
+      if let Err(e) = notify(&connection, summary, &body, &[]) {
+        eprintln!("Failed to send cleanup notification: {}", e);
+      }
+    }
+  });
+
+  Ok(())
+}
+
+fn format_size(bytes: u64) -> String {
+  const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+  let mut size = bytes as f64;
+  let mut unit = 0;
+  while size >= 1024.0 && unit < UNITS.len() - 1 {
+    size /= 1024.0;
+    unit += 1;
+  }
+  format!("{:.1} {}", size, UNITS[unit])
+}
@@ -0,0 +1,572 @@
+/* sys lib */
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/* services */
+use crate::services::helper_service::HelperService;
+
+/* models */
+use crate::models::{
+  AppError, BatchResultModel, DataValue, OrphanPackageModel, OrphanPackagesModel,
+  PackageCacheCleanModel, PackageCacheCleanResultModel, PackageCacheModel, PackageManager,
+  ResponseModel, ResponseStatus,
+};
+
+/* helpers */
+use serde_json::json;
+use walkdir::WalkDir;
+
+/// Package managers with the program that identifies them, checked in this order.
+const PACKAGE_MANAGERS: [(PackageManager, &str); 4] = [
+  (PackageManager::Apt, "apt-get"),
+  (PackageManager::Dnf, "dnf"),
+  (PackageManager::Pacman, "pacman"),
+  (PackageManager::Zypper, "zypper"),
+];
+const PACMAN_CONF: &str = "/etc/pacman.conf";
+const PACMAN_DEFAULT_CACHE: &str = "/var/cache/pacman/pkg";
+
+pub struct PackageService;
+
+#[allow(non_snake_case)]
+impl PackageService {
+  pub fn getPackageCache(&self) -> Result<ResponseModel, AppError> {
+    let cache = package_cache(detect_package_manager()?);
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "The {} cache holds {} packages in {} bytes",
+        cache.manager.as_str(),
+        cache.packageCount,
+        cache.totalSize
+      ),
+      data: DataValue::Object(serde_json::to_value(cache).unwrap_or(json!({}))),
+    })
+  }
+
+  /// Cleans the package cache through the privileged helper using the tool's own command,
+  /// so its lock and bookkeeping are respected.
+  pub fn cleanPackageCache(
+    &self,
+    clean: PackageCacheCleanModel,
+  ) -> Result<ResponseModel, AppError> {
+    let manager = match clean.manager {
+      Some(manager) => manager,
+      None => detect_package_manager()?,
+    };
+    let before = package_cache(manager);
+    let output = HelperService::connect()?.cleanPackageCache(manager, clean.keepVersions)?;
+    let after = package_cache(manager);
+    let result = PackageCacheCleanResultModel {
+      freedBytes: before.totalSize.saturating_sub(after.totalSize),
+      before,
+      after,
+      output,
+    };
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "Freed {} bytes from the {} cache",
+        result.freedBytes,
+        manager.as_str()
+      ),
+      data: DataValue::Object(serde_json::to_value(result).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn getOrphanPackages(&self) -> Result<ResponseModel, AppError> {
+    let manager = detect_package_manager()?;
+    let packages = orphan_packages(manager)?;
+    let result = OrphanPackagesModel {
+      manager,
+      totalSize: packages.iter().map(|p| p.installedSize).sum(),
+      packages,
+    };
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "{} packages are no longer needed, using {} bytes",
+        result.packages.len(),
+        result.totalSize
+      ),
+      data: DataValue::Object(serde_json::to_value(result).unwrap_or(json!({}))),
+    })
+  }
+
+  /// Removes the selected packages through the privileged helper, which refuses any that
+  /// are not unneeded dependencies.
+  pub fn removeOrphanPackages(&self, packages: Vec<String>) -> Result<ResponseModel, AppError> {
+    if packages.is_empty() {
+      return Ok(ResponseModel {
+        status: ResponseStatus::Success,
+        message: "No packages selected".to_string(),
+        data: DataValue::String("".to_string()),
+      });
+    }
+
+    let manager = detect_package_manager()?;
+    let mut batch = BatchResultModel::default();
+    for item in HelperService::connect()?.removePackages(manager, &packages)? {
+      batch.push(item);
+    }
+    if let Some(error) = batch.toError("Removed") {
+      return Err(error);
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Removed {} packages", batch.succeeded),
+      data: DataValue::Object(serde_json::to_value(batch).unwrap_or(json!({}))),
+    })
+  }
+}
+
+pub fn detect_package_manager() -> Result<PackageManager, AppError> {
+  let dirs: Vec<PathBuf> = env::var_os("PATH")
+    .map(|path| env::split_paths(&path).collect())
+    .unwrap_or_else(|| vec![PathBuf::from("/usr/bin"), PathBuf::from("/bin")]);
+  PACKAGE_MANAGERS
+    .iter()
+    .find(|(_, program)| dirs.iter().any(|dir| dir.join(program).is_file()))
+    .map(|(manager, _)| *manager)
+    .ok_or_else(|| AppError::notFound("No supported package manager found"))
+}
+
+/// Sizes the cache without root: package archives are world readable, and the partial
+/// downloads that are not are left out.
+pub fn package_cache(manager: PackageManager) -> PackageCacheModel {
+  let cache_dirs = package_cache_dirs(manager);
+  let mut cache = PackageCacheModel {
+    manager,
+    cacheDirs: cache_dirs
+      .iter()
+      .map(|dir| dir.to_string_lossy().to_string())
+      .collect(),
+    totalSize: 0,
+    packageCount: 0,
+    packageSize: 0,
+  };
+  for dir in &cache_dirs {
+    for entry in WalkDir::new(dir)
+      .into_iter()
+      .filter_map(|e| e.ok())
+      .filter(|e| e.file_type().is_file())
+    {
+      let Ok(metadata) = entry.metadata() else {
+        continue;
+      };
+      cache.totalSize += metadata.len();
+      if is_package_archive(entry.path()) {
+        cache.packageCount += 1;
+        cache.packageSize += metadata.len();
+      }
+    }
+  }
+  cache
+}
+
+fn package_cache_dirs(manager: PackageManager) -> Vec<PathBuf> {
+  match manager {
+    PackageManager::Apt => vec![PathBuf::from("/var/cache/apt/archives")],
+    // dnf5 keeps its cache apart from dnf4
+    PackageManager::Dnf => vec![
+      PathBuf::from("/var/cache/dnf"),
+      PathBuf::from("/var/cache/libdnf5"),
+    ],
+    PackageManager::Pacman => pacman_cache_dirs(),
+    PackageManager::Zypper => vec![PathBuf::from("/var/cache/zypp/packages")],
+  }
+}
+
+/// `CacheDir` entries of pacman.conf, which may list several directories.
+fn pacman_cache_dirs() -> Vec<PathBuf> {
+  let dirs: Vec<PathBuf> = fs::read_to_string(PACMAN_CONF)
+    .unwrap_or_default()
+    .lines()
+    .filter_map(|line| {
+      let (key, value) = line.split_once('=')?;
+      (key.trim() == "CacheDir").then_some(value)
+    })
+    .flat_map(|value| value.split_whitespace().map(PathBuf::from))
+    .collect();
+  if dirs.is_empty() {
+    return vec![PathBuf::from(PACMAN_DEFAULT_CACHE)];
+  }
+  dirs
+}
+
+fn is_package_archive(path: &Path) -> bool {
+  let name = path
+    .file_name()
+    .and_then(|n| n.to_str())
+    .unwrap_or_default();
+  name.ends_with(".deb")
+    || name.ends_with(".rpm")
+    || (name.contains(".pkg.tar") && !name.ends_with(".sig"))
+}
+
+/// Packages installed as dependencies that nothing requires anymore, as the tool's own
+/// autoremove sees them, largest first.
+pub fn orphan_packages(manager: PackageManager) -> Result<Vec<OrphanPackageModel>, AppError> {
+  let mut packages = match manager {
+    PackageManager::Apt => apt_orphans()?,
+    PackageManager::Pacman => pacman_orphans()?,
+    PackageManager::Dnf => {
+      let args = [
+        "-q",
+        "repoquery",
+        "--unneeded",
+        "--queryformat",
+        "%{name}\n",
+      ];
+      let output = run_query("dnf", &args)?;
+      if !output.status.success() {
+        return Err(AppError::command(
+          "dnf failed to list unneeded packages",
+          &output,
+        ));
+      }
+      rpm_packages(&unique_lines(&output))?
+    }
+    PackageManager::Zypper => {
+      let args = ["--non-interactive", "--quiet", "packages", "--unneeded"];
+      let output = run_query("zypper", &args)?;
+      if !output.status.success() {
+        return Err(AppError::command(
+          "zypper failed to list unneeded packages",
+          &output,
+        ));
+      }
+      rpm_packages(&parse_zypper_packages(&String::from_utf8_lossy(
+        &output.stdout,
+      )))?
+    }
+  };
+  packages.sort_by(|a, b| {
+    b.installedSize
+      .cmp(&a.installedSize)
+      .then_with(|| a.name.cmp(&b.name))
+  });
+  Ok(packages)
+}
+
+/// Reads what `apt-get autoremove` would remove from a simulated run, which needs no root.
+fn apt_orphans() -> Result<Vec<OrphanPackageModel>, AppError> {
+  let output = run_query("apt-get", &["--simulate", "autoremove"])?;
+  if !output.status.success() {
+    return Err(AppError::command(
+      "apt-get failed to plan autoremove",
+      &output,
+    ));
+  }
+  let removals = parse_apt_removals(&String::from_utf8_lossy(&output.stdout));
+  if removals.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  let mut args = vec![
+    "-W",
+    "-f",
+    "${binary:Package}\t${Package}\t${Installed-Size}\n",
+  ];
+  args.extend(removals.iter().map(|(name, _)| name.as_str()));
+  let output = run_query("dpkg-query", &args)?;
+  Ok(apt_packages(
+    removals,
+    &String::from_utf8_lossy(&output.stdout),
+  ))
+}
+
+/// Names and versions from `apt-get --simulate autoremove` lines such as
+/// `Remv libfoo1:i386 [1.2-3]`; multi-arch names keep their arch.
+fn parse_apt_removals(output: &str) -> Vec<(String, String)> {
+  output
+    .lines()
+    .filter_map(|line| {
+      let rest = line.strip_prefix("Remv ")?;
+      let (name, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+      let version = rest
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .map(|(version, _)| version)
+        .unwrap_or_default();
+      Some((name.to_string(), version.to_string()))
+    })
+    .collect()
+}
+
+/// Pairs apt removals with their sizes from `dpkg-query` rows of binary name, name and
+/// Installed-Size in KiB. Multi-arch packages may be named with or without their arch.
+fn apt_packages(removals: Vec<(String, String)>, sizes_output: &str) -> Vec<OrphanPackageModel> {
+  let mut sizes: HashMap<&str, u64> = HashMap::new();
+  for line in sizes_output.lines() {
+    let mut fields = line.split('\t');
+    let (Some(binary), Some(name), Some(size)) = (fields.next(), fields.next(), fields.next())
+    else {
+      continue;
+    };
+    let size = size.trim().parse::<u64>().unwrap_or(0) * 1024;
+    sizes.insert(binary, size);
+    sizes.entry(name).or_insert(size);
+  }
+
+  removals
+    .into_iter()
+    .map(|(name, version)| OrphanPackageModel {
+      installedSize: sizes
+        .get(name.as_str())
+        .or_else(|| sizes.get(name.split(':').next().unwrap_or_default()))
+        .copied()
+        .unwrap_or(0),
+      name,
+      version,
+    })
+    .collect()
+}
+
+fn pacman_orphans() -> Result<Vec<OrphanPackageModel>, AppError> {
+  let output = run_query("pacman", &["-Qdtq"])?;
+  // pacman exits with 1 and prints nothing when there are no orphans
+  if !output.status.success() && !output.stderr.is_empty() {
+    return Err(AppError::command("pacman failed to list orphans", &output));
+  }
+  let names = unique_lines(&output);
+  if names.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  let mut args = vec!["-Qi"];
+  args.extend(names.iter().map(String::as_str));
+  let output = run_query("pacman", &args)?;
+  Ok(parse_pacman_info(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Packages from `pacman -Qi` blocks. Wrapped values, such as further optional
+/// dependencies, are indented and never start a field.
+fn parse_pacman_info(output: &str) -> Vec<OrphanPackageModel> {
+  let mut packages: Vec<OrphanPackageModel> = Vec::new();
+  for line in output.lines().filter(|line| !line.starts_with(' ')) {
+    let Some((key, value)) = line.split_once(':') else {
+      continue;
+    };
+    let value = value.trim();
+    match key.trim() {
+      "Name" => packages.push(OrphanPackageModel {
+        name: value.to_string(),
+        version: String::new(),
+        installedSize: 0,
+      }),
+      "Version" => {
+        if let Some(package) = packages.last_mut() {
+          package.version = value.to_string();
+        }
+      }
+      "Installed Size" => {
+        if let Some(package) = packages.last_mut() {
+          package.installedSize = parse_pacman_size(value);
+        }
+      }
+      _ => {}
+    }
+  }
+  packages
+}
+
+/// Name, version and installed size of rpm packages; names that are not installed are
+/// left out.
+fn rpm_packages(names: &[String]) -> Result<Vec<OrphanPackageModel>, AppError> {
+  if names.is_empty() {
+    return Ok(Vec::new());
+  }
+  let mut args = vec![
+    "-q",
+    "--queryformat",
+    "%{NAME}\t%{VERSION}-%{RELEASE}\t%{SIZE}\n",
+  ];
+  args.extend(names.iter().map(String::as_str));
+  let output = run_query("rpm", &args)?;
+  Ok(parse_rpm_packages(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Rows of name, version-release and size in bytes from `rpm -q --queryformat`.
+fn parse_rpm_packages(output: &str) -> Vec<OrphanPackageModel> {
+  output
+    .lines()
+    .filter_map(|line| {
+      let mut fields = line.split('\t');
+      let (name, version, size) = (fields.next()?, fields.next()?, fields.next()?);
+      Some(OrphanPackageModel {
+        name: name.to_string(),
+        version: version.to_string(),
+        installedSize: size.trim().parse().unwrap_or(0),
+      })
+    })
+    .collect()
+}
+
+/// Installed package names from `zypper packages --unneeded`, whose table rows are
+/// status | repository | name | version | arch. A package from several repositories is
+/// listed once.
+fn parse_zypper_packages(output: &str) -> Vec<String> {
+  let mut names: Vec<String> = output
+    .lines()
+    .map(|line| line.split('|').map(str::trim).collect::<Vec<_>>())
+    .filter(|columns| columns.len() >= 5 && columns[0].starts_with('i'))
+    .map(|columns| columns[2].to_string())
+    .collect();
+  names.sort();
+  names.dedup();
+  names
+}
+
+/// Sizes as pacman prints them, such as `1.50 MiB`.
+fn parse_pacman_size(value: &str) -> u64 {
+  let mut parts = value.split_whitespace();
+  let number: f64 = parts
+    .next()
+    .and_then(|n| n.replace(',', ".").parse().ok())
+    .unwrap_or(0.0);
+  let unit: f64 = match parts.next().unwrap_or("B") {
+    "KiB" => 1024.0,
+    "MiB" => 1024.0 * 1024.0,
+    "GiB" => 1024.0 * 1024.0 * 1024.0,
+    "TiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+    _ => 1.0,
+  };
+  (number * unit) as u64
+}
+
+fn unique_lines(output: &Output) -> Vec<String> {
+  let mut seen = HashSet::new();
+  String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty() && seen.insert(line.to_string()))
+    .map(str::to_string)
+    .collect()
+}
+
+/// Runs a read-only package query in the C locale, which the parsers above expect.
+pub fn run_query(program: &str, args: &[&str]) -> Result<Output, AppError> {
+  Command::new(program)
+    .args(args)
+    .env("LC_ALL", "C")
+    .output()
+    .map_err(|e| AppError::spawn(program, &e))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_apt_removals_keeps_multiarch_names() {
+    let output = "\
+Reading package lists...
+The following packages will be REMOVED:
+  libfoo1 libbar2:i386
+Remv libfoo1 [1.2-3]
+Remv libbar2:i386 [2.0-1ubuntu1] [libbaz:i386 ]
+Remv linux-headers-6.8.0-40-generic [6.8.0-40.40]
+";
+    assert_eq!(
+      parse_apt_removals(output),
+      [
+        ("libfoo1".to_string(), "1.2-3".to_string()),
+        ("libbar2:i386".to_string(), "2.0-1ubuntu1".to_string()),
+        (
+          "linux-headers-6.8.0-40-generic".to_string(),
+          "6.8.0-40.40".to_string()
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn apt_packages_matches_sizes_with_and_without_arch() {
+    let removals = parse_apt_removals(
+      "Remv libfoo1 [1.2-3]\nRemv libbar2:i386 [2.0-1]\nRemv libqux0:amd64 [0.1]\nRemv gone [1]\n",
+    );
+    let sizes = "libfoo1\tlibfoo1\t100\nlibbar2:i386\tlibbar2\t20\nlibqux0\tlibqux0\t3\n";
+
+    let packages = apt_packages(removals, sizes);
+    let sizes: Vec<(&str, u64)> = packages
+      .iter()
+      .map(|p| (p.name.as_str(), p.installedSize))
+      .collect();
+    assert_eq!(
+      sizes,
+      [
+        ("libfoo1", 100 * 1024),
+        ("libbar2:i386", 20 * 1024),
+        ("libqux0:amd64", 3 * 1024),
+        ("gone", 0),
+      ]
+    );
+  }
+
+  #[test]
+  fn parse_pacman_info_reads_each_block() {
+    let output = "\
+Name            : python-foo
+Version         : 1.2.3-1
+Description     : Foo: a library
+Optional Deps   : python-bar: for bar support
+                  Name: not a field
+Installed Size  : 1,50 MiB
+
+Name            : libbaz
+Version         : 2:0.9-4
+Installed Size  : 512.00 KiB
+";
+    let packages = parse_pacman_info(output);
+    let fields: Vec<(&str, &str, u64)> = packages
+      .iter()
+      .map(|p| (p.name.as_str(), p.version.as_str(), p.installedSize))
+      .collect();
+    assert_eq!(
+      fields,
+      [
+        ("python-foo", "1.2.3-1", 1_572_864),
+        ("libbaz", "2:0.9-4", 524_288),
+      ]
+    );
+  }
+
+  #[test]
+  fn parse_pacman_size_handles_units_and_decimal_commas() {
+    assert_eq!(parse_pacman_size("1.50 MiB"), 1_572_864);
+    assert_eq!(parse_pacman_size("1,50 MiB"), 1_572_864);
+    assert_eq!(parse_pacman_size("2.00 GiB"), 2 * 1024 * 1024 * 1024);
+    assert_eq!(parse_pacman_size("12.00 B"), 12);
+    assert_eq!(parse_pacman_size("None"), 0);
+  }
+
+  #[test]
+  fn parse_zypper_packages_reads_installed_rows() {
+    let output = "\
+S  | Repository | Name        | Version    | Arch
+---+------------+-------------+------------+-------
+i  | repo-oss   | libfoo1     | 1.2-3.1    | x86_64
+i+ | repo-oss   | bar-tools   | 0.4-1.2    | noarch
+v  | repo-oss   | libfoo1     | 1.3-1.1    | x86_64
+i  | @System    | libfoo1     | 1.2-3.1    | x86_64
+";
+    assert_eq!(parse_zypper_packages(output), ["bar-tools", "libfoo1"]);
+  }
+
+  #[test]
+  fn parse_rpm_packages_reads_rows() {
+    let packages = parse_rpm_packages("libfoo\t1.2-3.fc40\t2048\nbroken line\n");
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0].name, "libfoo");
+    assert_eq!(packages[0].version, "1.2-3.fc40");
+    assert_eq!(packages[0].installedSize, 2048);
+  }
+}
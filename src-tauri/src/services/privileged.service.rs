@@ -0,0 +1,865 @@
+/* sys lib */
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::os::fd::{AsRawFd, OwnedFd};
+use std::path::{Component, Path};
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/* services */
+use crate::services::helper_service::{item_reply, ItemReply, HELPER_BUS_NAME, HELPER_PATH};
+use crate::services::kernel_service::installed_kernels;
+use crate::services::log_service::log_file_kind;
+use crate::services::package_service::{detect_package_manager, orphan_packages};
+use crate::services::system_service::run_batch_action;
+use crate::services::systemd_service::{dbus_error, SystemdService};
+
+/* models */
+use crate::models::{
+  AppError, ErrorCode, ItemResultModel, LogFileKind, PackageManager, ServiceAction, ServiceScope,
+};
+
+/* helpers */
+use rustix::fs::{
+  fstat, ftruncate, openat, statat, unlinkat, AtFlags, FileType, Mode, OFlags, CWD,
+};
+use zbus::blocking::fdo::DBusProxy;
+use zbus::blocking::{connection, Connection, Proxy};
+use zbus::message::{Flags, Header};
+use zbus::names::{BusName, UniqueName};
+use zbus::zvariant::Value;
+
+const LOG_ROOT: &str = "/var/log";
+const MAX_PATHS: usize = 10_000;
+const MAX_PACKAGES: usize = 1_000;
+const IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+const SESSION_TIMEOUT: Duration = Duration::from_secs(900);
+/// Every systemd unit type, so a session manages the same units as direct systemd calls.
+const UNIT_SUFFIXES: [&str; 11] = [
+  ".service",
+  ".socket",
+  ".device",
+  ".mount",
+  ".automount",
+  ".swap",
+  ".target",
+  ".path",
+  ".timer",
+  ".slice",
+  ".scope",
+];
+
+const POLKIT_DESTINATION: &str = "org.freedesktop.PolicyKit1";
+const POLKIT_PATH: &str = "/org/freedesktop/PolicyKit1/Authority";
+const POLKIT_INTERFACE: &str = "org.freedesktop.PolicyKit1.Authority";
+const POLKIT_ALLOW_USER_INTERACTION: u32 = 1;
+
+const ELEVATE_ACTION: &str = "com.tcs.cleanux.elevate";
+const MANAGE_UNITS_ACTION: &str = "com.tcs.cleanux.manage-units";
+const CLEAN_LOGS_ACTION: &str = "com.tcs.cleanux.clean-logs";
+const VACUUM_JOURNAL_ACTION: &str = "com.tcs.cleanux.vacuum-journal";
+const CLEAN_PACKAGE_CACHE_ACTION: &str = "com.tcs.cleanux.clean-package-cache";
+const REMOVE_PACKAGES_ACTION: &str = "com.tcs.cleanux.remove-packages";
+const REMOVE_KERNELS_ACTION: &str = "com.tcs.cleanux.remove-kernels";
+
+/// Actions an elevated session does not cover: uninstalling software is asked for every
+/// time, even in the middle of a session.
+const UNSESSIONED_ACTIONS: [&str; 2] = [REMOVE_PACKAGES_ACTION, REMOVE_KERNELS_ACTION];
+
+#[derive(Debug, zbus::DBusError)]
+#[zbus(prefix = "com.tcs.cleanux.Helper.Error")]
+pub enum HelperError {
+  #[zbus(error)]
+  ZBus(zbus::Error),
+  NotAuthorized(String),
+  Cancelled(String),
+  InvalidInput(String),
+  Failed(String),
+}
+
+impl From<AppError> for HelperError {
+  fn from(error: AppError) -> Self {
+    match error.code {
+      ErrorCode::InvalidInput | ErrorCode::OutsideAllowedRoot => {
+        HelperError::InvalidInput(error.message)
+      }
+      ErrorCode::PermissionDenied => HelperError::NotAuthorized(error.message),
+      ErrorCode::ElevationCancelled => HelperError::Cancelled(error.message),
+      _ => HelperError::Failed(error.message),
+    }
+  }
+}
+
+/// Calls in progress and the time the last one finished, so the helper can exit once it
+/// has been idle for a while; the bus activates it again on the next call.
+#[derive(Clone)]
+struct Activity(Arc<Mutex<(usize, Instant)>>);
+
+#[allow(non_snake_case)]
+impl Activity {
+  fn new() -> Self {
+    Self(Arc::new(Mutex::new((0, Instant::now()))))
+  }
+
+  fn begin(&self) {
+    if let Ok(mut activity) = self.0.lock() {
+      activity.0 += 1;
+    }
+  }
+
+  fn end(&self) {
+    if let Ok(mut activity) = self.0.lock() {
+      activity.0 = activity.0.saturating_sub(1);
+      activity.1 = Instant::now();
+    }
+  }
+
+  fn isIdle(&self) -> bool {
+    self
+      .0
+      .lock()
+      .map(|activity| activity.0 == 0 && activity.1.elapsed() >= IDLE_TIMEOUT)
+      .unwrap_or(true)
+  }
+}
+
+/// Elevated sessions by the caller's unique bus name, with the time each was last used.
+/// A session expires after `SESSION_TIMEOUT` without use or once its caller disconnects.
+#[derive(Clone)]
+struct Sessions(Arc<Mutex<HashMap<String, Instant>>>);
+
+#[allow(non_snake_case)]
+impl Sessions {
+  fn new() -> Self {
+    Self(Arc::new(Mutex::new(HashMap::new())))
+  }
+
+  fn open(&self, name: &str) {
+    if let Ok(mut sessions) = self.0.lock() {
+      sessions.insert(name.to_string(), Instant::now());
+    }
+  }
+
+  fn close(&self, name: &str) -> bool {
+    self
+      .0
+      .lock()
+      .is_ok_and(|mut sessions| sessions.remove(name).is_some())
+  }
+
+  /// Seconds left in the caller's session, which using it extends.
+  fn touch(&self, name: &str) -> u64 {
+    let Ok(mut sessions) = self.0.lock() else {
+      return 0;
+    };
+    match sessions.get_mut(name) {
+      Some(lastUsed) if lastUsed.elapsed() < SESSION_TIMEOUT => {
+        *lastUsed = Instant::now();
+        SESSION_TIMEOUT.as_secs()
+      }
+      _ => {
+        sessions.remove(name);
+        0
+      }
+    }
+  }
+
+  fn remaining(&self, name: &str) -> u64 {
+    self
+      .0
+      .lock()
+      .ok()
+      .and_then(|sessions| sessions.get(name).copied())
+      .map_or(0, |lastUsed| {
+        SESSION_TIMEOUT.saturating_sub(lastUsed.elapsed()).as_secs()
+      })
+  }
+
+  fn prune(&self, bus: &Connection) {
+    let Ok(mut sessions) = self.0.lock() else {
+      return;
+    };
+    let proxy = DBusProxy::new(bus).ok();
+    sessions.retain(|name, lastUsed| {
+      let connected = proxy.as_ref().is_some_and(|proxy| {
+        BusName::try_from(name.as_str())
+          .ok()
+          .and_then(|name| proxy.name_has_owner(name).ok())
+          .unwrap_or(false)
+      });
+      if !connected || lastUsed.elapsed() >= SESSION_TIMEOUT {
+        eprintln!("[{}] elevated session ended", name);
+        return false;
+      }
+      true
+    });
+  }
+
+  fn isEmpty(&self) -> bool {
+    self.0.lock().map_or(true, |sessions| sessions.is_empty())
+  }
+}
+
+/// Identity of an authorized caller, used to log what was done on whose behalf.
+struct Caller {
+  name: String,
+  uid: Option<u32>,
+}
+
+impl Caller {
+  fn log(&self, message: impl std::fmt::Display) {
+    match self.uid {
+      Some(uid) => eprintln!("[{} uid={}] {}", self.name, uid, message),
+      None => eprintln!("[{}] {}", self.name, message),
+    }
+  }
+}
+
+/// Root side of `HelperService`. It accepts only the operations below, checks each
+/// against its own polkit action unless the caller has an elevated session, and validates
+/// every argument itself, whatever the caller already checked.
+pub struct PrivilegedHelper {
+  bus: Connection,
+  activity: Activity,
+  sessions: Sessions,
+}
+
+#[allow(non_snake_case)]
+#[zbus::interface(name = "com.tcs.cleanux.Helper1")]
+impl PrivilegedHelper {
+  /// Authenticates the caller for `com.tcs.cleanux.elevate` and opens an elevated session
+  /// for it. Returns the seconds the session lasts without use.
+  fn beginSession(&self, #[zbus(header)] header: Header<'_>) -> Result<u64, HelperError> {
+    self.activity.begin();
+    let result = self
+      .checkAuthorization(&header, ELEVATE_ACTION)
+      .map(|caller| {
+        self.sessions.open(&caller.name);
+        caller.log("elevated session opened");
+        SESSION_TIMEOUT.as_secs()
+      });
+    self.activity.end();
+    result
+  }
+
+  fn endSession(&self, #[zbus(header)] header: Header<'_>) -> Result<(), HelperError> {
+    if let Some(sender) = header.sender() {
+      if self.sessions.close(sender.as_str()) {
+        eprintln!("[{}] elevated session closed", sender);
+      }
+    }
+    Ok(())
+  }
+
+  /// Seconds left in the caller's elevated session, or 0 without one.
+  fn sessionStatus(&self, #[zbus(header)] header: Header<'_>) -> u64 {
+    header
+      .sender()
+      .map_or(0, |sender| self.sessions.remaining(sender.as_str()))
+  }
+
+  /// Applies a service action to system units. Returns unit, success, error code and
+  /// message for each unit.
+  fn manageUnits(
+    &self,
+    #[zbus(header)] header: Header<'_>,
+    action: String,
+    units: Vec<String>,
+  ) -> Result<Vec<ItemReply>, HelperError> {
+    self.activity.begin();
+    let result = self
+      .authorize(&header, MANAGE_UNITS_ACTION)
+      .and_then(|caller| {
+        let serviceAction: ServiceAction =
+          serde_json::from_value(serde_json::Value::String(action.clone())).map_err(|_| {
+            HelperError::InvalidInput(format!("Unsupported service action: {}", action))
+          })?;
+        let systemd = SystemdService::connect(ServiceScope::System)?;
+
+        let mut items: Vec<ItemResultModel> = Vec::new();
+        let mut valid = Vec::new();
+        for unit in units {
+          match validate_unit_name(&unit) {
+            Ok(()) => valid.push(unit),
+            Err(error) => items.push(ItemResultModel::failed(unit, error)),
+          }
+        }
+        if !valid.is_empty() {
+          items.extend(run_batch_action(&systemd, serviceAction, &valid).items);
+        }
+
+        Ok(
+          items
+            .into_iter()
+            .map(|item| {
+              match &item.message {
+                Some(message) => {
+                  caller.log(format!("{} {} failed: {}", action, item.item, message))
+                }
+                None => caller.log(format!("{} {}", action, item.item)),
+              }
+              item_reply(item)
+            })
+            .collect(),
+        )
+      });
+    self.activity.end();
+    result
+  }
+
+  /// Cleans logs under `/var/log`: rotated archives are deleted and active logs truncated
+  /// in place, while login accounting databases and journal files are refused. Returns
+  /// path, success, error code and message for each requested path.
+  fn cleanLogs(
+    &self,
+    #[zbus(header)] header: Header<'_>,
+    paths: Vec<String>,
+  ) -> Result<Vec<ItemReply>, HelperError> {
+    self.activity.begin();
+    let result = self
+      .authorize(&header, CLEAN_LOGS_ACTION)
+      .and_then(|caller| {
+        if paths.len() > MAX_PATHS {
+          caller.log(format!("cleanLogs refused: {} paths", paths.len()));
+          return Err(HelperError::InvalidInput(format!(
+            "At most {} paths can be cleaned at once",
+            MAX_PATHS
+          )));
+        }
+
+        Ok(
+          paths
+            .into_iter()
+            .map(|path| match clean_log(&path) {
+              Ok(verb) => {
+                caller.log(format!("{} {}", verb, path));
+                item_reply(ItemResultModel::succeeded(path))
+              }
+              Err(error) => {
+                caller.log(format!("refused {}: {}", path, error.message));
+                item_reply(ItemResultModel::failed(path, error))
+              }
+            })
+            .collect(),
+        )
+      });
+    self.activity.end();
+    result
+  }
+
+  /// Removes archived journal files until the journal is below `max_bytes`, holds nothing
+  /// older than `max_age_seconds` and has at most `max_files` archives; zero leaves that
+  /// limit out.
+  fn vacuumJournal(
+    &self,
+    #[zbus(header)] header: Header<'_>,
+    max_bytes: u64,
+    max_age_seconds: u64,
+    max_files: u32,
+  ) -> Result<String, HelperError> {
+    self.activity.begin();
+    let result = self
+      .authorize(&header, VACUUM_JOURNAL_ACTION)
+      .and_then(|caller| {
+        if max_bytes == 0 && max_age_seconds == 0 && max_files == 0 {
+          return Err(HelperError::InvalidInput(
+            "A size, age or file count limit is required".to_string(),
+          ));
+        }
+        let mut args = vec![];
+        if max_bytes > 0 {
+          args.push(format!("--vacuum-size={}", max_bytes));
+        }
+        if max_age_seconds > 0 {
+          args.push(format!("--vacuum-time={}s", max_age_seconds));
+        }
+        if max_files > 0 {
+          args.push(format!("--vacuum-files={}", max_files));
+        }
+        caller.log(format!("journalctl {}", args.join(" ")));
+        run_command("journalctl", &args).map_err(HelperError::from)
+      });
+    self.activity.end();
+    result
+  }
+
+  /// Cleans the download cache of one of the supported package managers, keeping the
+  /// newest `keep_versions` of each package where the tool can; a negative count cleans
+  /// the way the tool does by default.
+  fn cleanPackageCache(
+    &self,
+    #[zbus(header)] header: Header<'_>,
+    manager: String,
+    keep_versions: i32,
+  ) -> Result<String, HelperError> {
+    self.activity.begin();
+    let result = self
+      .authorize(&header, CLEAN_PACKAGE_CACHE_ACTION)
+      .and_then(|caller| {
+        let keep = u32::try_from(keep_versions).ok();
+        let (program, args) = package_cache_command(&manager, keep)?;
+        caller.log(format!("{} {}", program, args.join(" ")));
+        run_command(program, &args).map_err(|e| match e.code {
+          ErrorCode::NotFound if program == "paccache" => HelperError::Failed(
+            "Keeping package versions needs paccache from pacman-contrib".to_string(),
+          ),
+          _ => HelperError::from(e),
+        })
+      });
+    self.activity.end();
+    result
+  }
+
+  /// Removes installed packages that nothing requires anymore. Every name is checked
+  /// against the package manager's own list of unneeded packages first, so nothing else
+  /// can be removed this way. Returns package, success, error code and message for each.
+  fn removePackages(
+    &self,
+    #[zbus(header)] header: Header<'_>,
+    manager: String,
+    packages: Vec<String>,
+  ) -> Result<Vec<ItemReply>, HelperError> {
+    self.activity.begin();
+    let result = self
+      .authorize(&header, REMOVE_PACKAGES_ACTION)
+      .and_then(|caller| {
+        let manager = PackageManager::parse(&manager).ok_or_else(|| {
+          HelperError::InvalidInput(format!("Unsupported package manager: {}", manager))
+        })?;
+        if packages.len() > MAX_PACKAGES {
+          caller.log(format!(
+            "removePackages refused: {} packages",
+            packages.len()
+          ));
+          return Err(HelperError::InvalidInput(format!(
+            "At most {} packages can be removed at once",
+            MAX_PACKAGES
+          )));
+        }
+
+        let orphans: HashSet<String> = orphan_packages(manager)?
+          .into_iter()
+          .map(|package| package.name)
+          .collect();
+        let (allowed, refused): (Vec<String>, Vec<String>) = packages
+          .into_iter()
+          .partition(|package| orphans.contains(package));
+        let mut replies: Vec<ItemReply> = refused
+          .into_iter()
+          .map(|package| {
+            caller.log(format!("refused {}: not an unneeded package", package));
+            let error = AppError::invalidInput(format!("{} is not an unneeded package", package));
+            item_reply(ItemResultModel::failed(package, error))
+          })
+          .collect();
+        if allowed.is_empty() {
+          return Ok(replies);
+        }
+
+        let (program, args) = package_remove_command(manager, &allowed);
+        caller.log(format!("{} {}", program, args.join(" ")));
+        // The tool removes the whole selection in one transaction, or nothing
+        let result = run_command(program, &args);
+        replies.extend(allowed.into_iter().map(|package| {
+          item_reply(match &result {
+            Ok(_) => ItemResultModel::succeeded(package),
+            Err(error) => ItemResultModel::failed(package, error.clone()),
+          })
+        }));
+        Ok(replies)
+      });
+    self.activity.end();
+    result
+  }
+
+  /// Uninstalls the packages of old kernels. The running and latest kernels are refused,
+  /// as is any kernel sharing a package with them. Returns version, success, error code
+  /// and message for each requested version.
+  fn removeKernels(
+    &self,
+    #[zbus(header)] header: Header<'_>,
+    versions: Vec<String>,
+  ) -> Result<Vec<ItemReply>, HelperError> {
+    self.activity.begin();
+    let result = self
+      .authorize(&header, REMOVE_KERNELS_ACTION)
+      .and_then(|caller| {
+        let manager = detect_package_manager()?;
+        let kernels = installed_kernels();
+        let protected: HashSet<&String> = kernels
+          .iter()
+          .filter(|kernel| kernel.protected)
+          .flat_map(|kernel| &kernel.packages)
+          .collect();
+
+        let mut replies = Vec::new();
+        let mut allowed: Vec<(String, Vec<String>)> = Vec::new();
+        for version in versions {
+          let checked = match kernels.iter().find(|kernel| kernel.version == version) {
+            None => Err(AppError::notFound(format!(
+              "Kernel {} is not installed",
+              version
+            ))),
+            Some(kernel) if kernel.protected => Err(AppError::invalidInput(format!(
+              "Kernel {} is running or the latest one",
+              version
+            ))),
+            Some(kernel) if kernel.packages.is_empty() => Err(AppError::invalidInput(format!(
+              "Kernel {} was not installed by a package",
+              version
+            ))),
+            Some(kernel) if kernel.packages.iter().any(|p| protected.contains(p)) => {
+              Err(AppError::invalidInput(format!(
+                "Kernel {} shares a package with a protected kernel",
+                version
+              )))
+            }
+            Some(kernel) => Ok(kernel),
+          };
+          match checked {
+            Ok(kernel) => allowed.push((version, kernel.packages.clone())),
+            Err(error) => {
+              caller.log(format!("refused kernel {}: {}", version, error.message));
+              replies.push(item_reply(ItemResultModel::failed(version, error)));
+            }
+          }
+        }
+        if allowed.is_empty() {
+          return Ok(replies);
+        }
+
+        let packages: Vec<String> = allowed
+          .iter()
+          .flat_map(|(_, packages)| packages.iter().cloned())
+          .collect::<BTreeSet<_>>()
+          .into_iter()
+          .collect();
+        let (program, args) = package_remove_command(manager, &packages);
+        caller.log(format!("{} {}", program, args.join(" ")));
+        // Kernels share one transaction, so they are removed together or not at all
+        let result = run_command(program, &args);
+        replies.extend(allowed.into_iter().map(|(version, _)| {
+          item_reply(match &result {
+            Ok(_) => ItemResultModel::succeeded(version),
+            Err(error) => ItemResultModel::failed(version, error.clone()),
+          })
+        }));
+        Ok(replies)
+      });
+    self.activity.end();
+    result
+  }
+}
+
+#[allow(non_snake_case)]
+impl PrivilegedHelper {
+  fn new(activity: Activity, sessions: Sessions) -> Result<Self, AppError> {
+    // A connection of its own, since the serving one must not block on its own replies
+    let bus =
+      Connection::system().map_err(|e| dbus_error("Failed to connect to the system bus", e))?;
+    Ok(Self {
+      bus,
+      activity,
+      sessions,
+    })
+  }
+
+  /// Authorizes a request through the caller's elevated session, or polkit without one.
+  fn authorize(&self, header: &Header<'_>, action: &str) -> Result<Caller, HelperError> {
+    let sender = header
+      .sender()
+      .ok_or_else(|| HelperError::NotAuthorized("The caller has no bus name".to_string()))?;
+    if !UNSESSIONED_ACTIONS.contains(&action) && self.sessions.touch(sender.as_str()) > 0 {
+      return Ok(self.caller(sender));
+    }
+    self.checkAuthorization(header, action)
+  }
+
+  fn caller(&self, sender: &UniqueName<'_>) -> Caller {
+    Caller {
+      name: sender.to_string(),
+      uid: DBusProxy::new(&self.bus).ok().and_then(|proxy| {
+        proxy
+          .get_connection_unix_user(BusName::Unique(sender.clone()))
+          .ok()
+      }),
+    }
+  }
+
+  fn checkAuthorization(&self, header: &Header<'_>, action: &str) -> Result<Caller, HelperError> {
+    let sender = header
+      .sender()
+      .ok_or_else(|| HelperError::NotAuthorized("The caller has no bus name".to_string()))?;
+    let caller = self.caller(sender);
+
+    let interactive = header
+      .primary()
+      .flags()
+      .contains(Flags::AllowInteractiveAuth);
+    let subject = (
+      "system-bus-name",
+      HashMap::from([("name", Value::from(sender.as_str()))]),
+    );
+    let details: HashMap<&str, &str> = HashMap::new();
+    let flags = if interactive {
+      POLKIT_ALLOW_USER_INTERACTION
+    } else {
+      0
+    };
+
+    let polkit = Proxy::new(&self.bus, POLKIT_DESTINATION, POLKIT_PATH, POLKIT_INTERFACE)?;
+    let (authorized, _, result): (bool, bool, HashMap<String, String>) =
+      polkit.call("CheckAuthorization", &(subject, action, details, flags, ""))?;
+    if authorized {
+      return Ok(caller);
+    }
+
+    caller.log(format!("not authorized for {}", action));
+    if result.get("polkit.dismissed").is_some_and(|v| v == "true") {
+      return Err(HelperError::Cancelled(
+        "Authentication was cancelled".to_string(),
+      ));
+    }
+    Err(HelperError::NotAuthorized(format!(
+      "Not authorized for {}",
+      action
+    )))
+  }
+}
+
+/// Serves the helper on the system bus until it has been idle for `IDLE_TIMEOUT` with no
+/// elevated session open.
+pub fn run_helper() -> Result<(), AppError> {
+  let activity = Activity::new();
+  let sessions = Sessions::new();
+  let helper = PrivilegedHelper::new(activity.clone(), sessions.clone())?;
+  let bus = helper.bus.clone();
+  let _connection = connection::Builder::system()
+    .and_then(|builder| builder.name(HELPER_BUS_NAME))
+    .and_then(|builder| builder.serve_at(HELPER_PATH, helper))
+    .and_then(|builder| builder.build())
+    .map_err(|e| dbus_error("Failed to serve the privileged helper", e))?;
+
+  loop {
+    thread::sleep(Duration::from_secs(5));
+    sessions.prune(&bus);
+    if sessions.isEmpty() && activity.isIdle() {
+      return Ok(());
+    }
+  }
+}
+
+/// Deletes a rotated log or truncates an active one, returning what was done. Both work
+/// relative to the opened parent directory, so a directory swapped for a symlink after
+/// the check cannot redirect them.
+fn clean_log(path: &str) -> Result<&'static str, AppError> {
+  let path = Path::new(path);
+  if !path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+    return Err(AppError::invalidInput(format!(
+      "{} is not an absolute path",
+      path.display()
+    )));
+  }
+  let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+    return Err(AppError::invalidInput(format!(
+      "{} is not a file path",
+      path.display()
+    )));
+  };
+  let kind = log_file_kind(path);
+  match kind {
+    LogFileKind::Accounting => {
+      return Err(AppError::invalidInput(format!(
+        "{} is a login accounting database",
+        path.display()
+      )))
+    }
+    LogFileKind::Journal => {
+      return Err(AppError::invalidInput(format!(
+        "{} is a journal file; vacuum the journal instead",
+        path.display()
+      )))
+    }
+    _ => {}
+  }
+
+  let dir: OwnedFd = openat(
+    CWD,
+    parent,
+    OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+    Mode::empty(),
+  )
+  .map_err(|e| AppError::io(parent.display(), &e.into()))?;
+  let opened = fs::read_link(format!("/proc/self/fd/{}", dir.as_raw_fd()))
+    .map_err(|e| AppError::io(parent.display(), &e))?;
+  if !opened.starts_with(LOG_ROOT) {
+    return Err(AppError::new(
+      ErrorCode::OutsideAllowedRoot,
+      format!("{} is outside {}", path.display(), LOG_ROOT),
+    ));
+  }
+
+  let not_regular = || AppError::invalidInput(format!("{} is not a regular file", path.display()));
+  if kind == LogFileKind::Rotated {
+    let stat = statat(&dir, name, AtFlags::SYMLINK_NOFOLLOW)
+      .map_err(|e| AppError::io(path.display(), &e.into()))?;
+    if FileType::from_raw_mode(stat.st_mode) != FileType::RegularFile {
+      return Err(not_regular());
+    }
+    unlinkat(&dir, name, AtFlags::empty()).map_err(|e| AppError::io(path.display(), &e.into()))?;
+    return Ok("deleted");
+  }
+
+  // Daemons keep writing at their offset in O_APPEND logs, so truncating in place frees
+  // the space at once where deleting would only free it once they reopen the file
+  let file = openat(
+    &dir,
+    name,
+    OFlags::WRONLY | OFlags::NOFOLLOW | OFlags::NONBLOCK | OFlags::NOCTTY | OFlags::CLOEXEC,
+    Mode::empty(),
+  )
+  .map_err(|e| AppError::io(path.display(), &e.into()))?;
+  let stat = fstat(&file).map_err(|e| AppError::io(path.display(), &e.into()))?;
+  if FileType::from_raw_mode(stat.st_mode) != FileType::RegularFile {
+    return Err(not_regular());
+  }
+  // A hard link in /var/log may share its data with a file anywhere on the filesystem
+  if stat.st_nlink != 1 {
+    return Err(AppError::invalidInput(format!(
+      "{} has other hard links",
+      path.display()
+    )));
+  }
+  ftruncate(&file, 0).map_err(|e| AppError::io(path.display(), &e.into()))?;
+  Ok("truncated")
+}
+
+fn validate_unit_name(name: &str) -> Result<(), AppError> {
+  let valid = name.len() <= 256
+    && name
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, ':' | '-' | '_' | '.' | '@' | '\\'))
+    && UNIT_SUFFIXES
+      .iter()
+      .any(|suffix| name.len() > suffix.len() && name.ends_with(suffix));
+  if !valid {
+    return Err(AppError::invalidInput(format!(
+      "Invalid unit name: {}",
+      name
+    )));
+  }
+  Ok(())
+}
+
+fn package_cache_command(
+  manager: &str,
+  keep_versions: Option<u32>,
+) -> Result<(&'static str, Vec<String>), HelperError> {
+  let (program, args): (&str, Vec<String>) = match (manager, keep_versions) {
+    ("apt", None) => ("apt-get", vec!["clean".to_string()]),
+    ("dnf", None) => ("dnf", vec!["clean".to_string(), "packages".to_string()]),
+    // Removes packages that are no longer installed; `-Scc` would need a confirmation
+    ("pacman", None) => ("pacman", vec!["-Sc".to_string(), "--noconfirm".to_string()]),
+    ("pacman", Some(keep)) => (
+      "paccache",
+      vec!["-r".to_string(), "-k".to_string(), keep.to_string()],
+    ),
+    ("zypper", None) => (
+      "zypper",
+      vec![
+        "--non-interactive".to_string(),
+        "clean".to_string(),
+        "--all".to_string(),
+      ],
+    ),
+    ("apt" | "dnf" | "zypper", Some(_)) => {
+      return Err(HelperError::InvalidInput(format!(
+        "{} cannot keep package versions; it only clears its whole cache",
+        manager
+      )))
+    }
+    _ => {
+      return Err(HelperError::InvalidInput(format!(
+        "Unsupported package manager: {}",
+        manager
+      )))
+    }
+  };
+  Ok((program, args))
+}
+
+fn package_remove_command(
+  manager: PackageManager,
+  packages: &[String],
+) -> (&'static str, Vec<String>) {
+  let (program, options): (&str, &[&str]) = match manager {
+    PackageManager::Apt => ("apt-get", &["-y", "remove"]),
+    PackageManager::Dnf => ("dnf", &["-y", "remove"]),
+    // Only the selection; dependencies it leaves unneeded show up in the next listing
+    PackageManager::Pacman => ("pacman", &["-Rn", "--noconfirm"]),
+    PackageManager::Zypper => ("zypper", &["--non-interactive", "remove"]),
+  };
+  let mut args: Vec<String> = options.iter().map(|o| o.to_string()).collect();
+  args.extend(packages.iter().cloned());
+  (program, args)
+}
+
+fn run_command(program: &str, args: &[String]) -> Result<String, AppError> {
+  // No one can answer a debconf prompt from here
+  let output = Command::new(program)
+    .args(args)
+    .env("DEBIAN_FRONTEND", "noninteractive")
+    .output()
+    .map_err(|e| AppError::spawn(program, &e))?;
+  if !output.status.success() {
+    return Err(AppError::command(format!("{} failed", program), &output));
+  }
+
+  // journalctl reports what it freed on stderr
+  let mut text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  if !stderr.trim().is_empty() {
+    if !text.is_empty() {
+      text.push('\n');
+    }
+    text.push_str(stderr.trim());
+  }
+  Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn validate_unit_name_accepts_every_unit_type() {
+    for name in [
+      "sshd.service",
+      "fstrim.timer",
+      "home.mount",
+      "proc-sys-fs-binfmt_misc.automount",
+      "dev-sda2.swap",
+      "graphical.target",
+      "dev-disk-by\\x2duuid-1234.device",
+      "user-1000.slice",
+      "getty@tty1.service",
+    ] {
+      assert!(validate_unit_name(name).is_ok(), "{}", name);
+    }
+  }
+
+  #[test]
+  fn validate_unit_name_rejects_other_names() {
+    for name in [
+      "sshd",
+      ".service",
+      "sshd.conf",
+      "a b.service",
+      "../x.service/",
+    ] {
+      assert!(validate_unit_name(name).is_err(), "{}", name);
+    }
+  }
+}
@@ -0,0 +1,240 @@
+/* services */
+use crate::services::cleaner_service::{plan_token, CleanerService, CleanupPlan};
+use crate::services::settings_service::{SettingsService, SettingsState};
+
+/* models */
+use crate::models::{
+  AppError, CleanupCategory, CleanupPreviewModel, CleanupProfileModel, CleanupResultModel,
+  CleanupRuleModel, DataValue, ProfileRunModel, ResponseModel, ResponseStatus,
+};
+
+/* helpers */
+use serde_json::json;
+
+pub struct ProfileService {
+  settings: SettingsState,
+}
+
+#[allow(non_snake_case)]
+impl ProfileService {
+  pub fn new(settings: SettingsState) -> Self {
+    Self { settings }
+  }
+
+  pub fn getProfiles(&self) -> Result<ResponseModel, AppError> {
+    let profiles: Vec<CleanupProfileModel> = builtin_profiles()
+      .into_iter()
+      .chain(self.settings.get().profiles)
+      .collect();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Found {} profiles", profiles.len()),
+      data: DataValue::Array(
+        profiles
+          .into_iter()
+          .map(|p| serde_json::to_value(p).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
+  }
+
+  pub fn saveProfile(&self, mut profile: CleanupProfileModel) -> Result<ResponseModel, AppError> {
+    profile.builtIn = false;
+    let saved = profile.clone();
+    SettingsService.applyChange(&self.settings, |settings| {
+      match settings.profiles.iter_mut().find(|p| p.id == profile.id) {
+        Some(existing) => *existing = profile,
+        None => settings.profiles.push(profile),
+      }
+      Ok(())
+    })?;
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Profile {} saved", saved.name),
+      data: DataValue::Object(serde_json::to_value(saved).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn removeProfile(&self, id: &str) -> Result<ResponseModel, AppError> {
+    SettingsService.applyChange(&self.settings, |settings| {
+      let count = settings.profiles.len();
+      settings.profiles.retain(|p| p.id != id);
+      if settings.profiles.len() == count {
+        return Err(AppError::notFound(format!("Profile {} not found", id)));
+      }
+      Ok(())
+    })?;
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Profile {} removed", id),
+      data: DataValue::String(id.to_string()),
+    })
+  }
+
+  /// Previews a profile, or runs it when given the `planToken` of its preview. A token
+  /// that no longer matches the files found, because they changed since the preview, is
+  /// refused with the new preview so it can be confirmed again.
+  pub fn runProfile(&self, id: &str, planToken: Option<&str>) -> Result<ResponseModel, AppError> {
+    let (profile, plans) = self.planProfile(id)?;
+    let token = plan_token(&plans);
+    let dryRun = planToken.is_none();
+    if planToken.is_some_and(|confirmed| confirmed != token) {
+      let run = self.runPlans(profile, plans, true);
+      return Err(
+        AppError::invalidInput("The files to clean changed since the preview").withData(
+          DataValue::Object(serde_json::to_value(run).unwrap_or(json!({}))),
+        ),
+      );
+    }
+    let run = self.runPlans(profile, plans, dryRun);
+    let failed = run.results.iter().any(|r| !r.errors.is_empty());
+
+    Ok(ResponseModel {
+      status: if failed {
+        ResponseStatus::Warning
+      } else {
+        ResponseStatus::Success
+      },
+      message: if dryRun {
+        format!(
+          "{} files ({} bytes) would be removed",
+          run.totalFiles, run.totalSize
+        )
+      } else {
+        format!(
+          "Removed {} of {} files, freed {} bytes",
+          run.removedFiles, run.totalFiles, run.freedBytes
+        )
+      },
+      data: DataValue::Object(serde_json::to_value(run).unwrap_or(json!({}))),
+    })
+  }
+
+  /// Plans and runs a profile without a preview, for schedules and other unattended runs.
+  pub fn executeProfile(&self, id: &str, dryRun: bool) -> Result<ProfileRunModel, AppError> {
+    let (profile, plans) = self.planProfile(id)?;
+    Ok(self.runPlans(profile, plans, dryRun))
+  }
+
+  fn planProfile(&self, id: &str) -> Result<(CleanupProfileModel, Vec<CleanupPlan>), AppError> {
+    let profile = self
+      .findProfile(id)
+      .ok_or_else(|| AppError::notFound(format!("Profile {} not found", id)))?;
+    let cleanerService = CleanerService::new(self.settings.get());
+
+    let plans = profile
+      .rules
+      .iter()
+      .map(|rule| cleanerService.planCleanupRule(rule))
+      .collect::<Result<Vec<_>, _>>()?;
+    Ok((profile, plans))
+  }
+
+  fn runPlans(
+    &self,
+    profile: CleanupProfileModel,
+    plans: Vec<CleanupPlan>,
+    dryRun: bool,
+  ) -> ProfileRunModel {
+    let cleanerService = CleanerService::new(self.settings.get());
+    let planToken = plan_token(&plans);
+    let preview: Vec<CleanupPreviewModel> = plans.iter().map(|plan| plan.preview()).collect();
+
+    let results: Vec<CleanupResultModel> = if dryRun {
+      Vec::new()
+    } else {
+      plans
+        .into_iter()
+        .map(|plan| cleanerService.executeCleanupPlan(plan))
+        .collect()
+    };
+
+    ProfileRunModel {
+      profileId: profile.id,
+      dryRun,
+      planToken,
+      totalFiles: preview.iter().map(|p| p.fileCount).sum(),
+      totalSize: preview.iter().map(|p| p.totalSize).sum(),
+      removedFiles: results.iter().map(|r| r.removedFiles).sum(),
+      freedBytes: results.iter().map(|r| r.freedBytes).sum(),
+      preview,
+      results,
+    }
+  }
+
+  fn findProfile(&self, id: &str) -> Option<CleanupProfileModel> {
+    builtin_profiles()
+      .into_iter()
+      .chain(self.settings.get().profiles)
+      .find(|p| p.id == id)
+  }
+}
+
+pub fn builtin_profiles() -> Vec<CleanupProfileModel> {
+  let rule = |category, older_than_days| CleanupRuleModel {
+    category,
+    olderThanDays: older_than_days,
+    largerThanBytes: None,
+    excludedApps: Vec::new(),
+  };
+  let browsers = ["mozilla", "google-chrome", "chromium", "BraveSoftware"]
+    .iter()
+    .map(|app| app.to_string())
+    .collect();
+
+  vec![
+    CleanupProfileModel {
+      id: "quick".to_string(),
+      name: "Quick".to_string(),
+      description: "Old trash, stale cache and orphaned thumbnails".to_string(),
+      rules: vec![
+        rule(CleanupCategory::Trash, Some(30)),
+        CleanupRuleModel {
+          excludedApps: browsers,
+          ..rule(CleanupCategory::Cache, Some(7))
+        },
+        rule(CleanupCategory::ThumbnailOrphans, None),
+      ],
+      builtIn: true,
+    },
+    CleanupProfileModel {
+      id: "deep".to_string(),
+      name: "Deep".to_string(),
+      description: "Everything Cleanux can clean without elevation".to_string(),
+      rules: vec![
+        rule(CleanupCategory::Trash, None),
+        rule(CleanupCategory::Cache, None),
+        rule(CleanupCategory::ThumbnailOrphans, None),
+        rule(CleanupCategory::PipCache, None),
+        rule(CleanupCategory::NpmCache, None),
+        rule(CleanupCategory::CargoCache, None),
+      ],
+      builtIn: true,
+    },
+    CleanupProfileModel {
+      id: "developer".to_string(),
+      name: "Developer".to_string(),
+      description: "Package manager caches of pip, npm and cargo".to_string(),
+      rules: vec![
+        rule(CleanupCategory::PipCache, None),
+        rule(CleanupCategory::NpmCache, None),
+        rule(CleanupCategory::CargoCache, Some(30)),
+      ],
+      builtIn: true,
+    },
+    CleanupProfileModel {
+      id: "before-backup".to_string(),
+      name: "Before backup".to_string(),
+      description: "Trash and caches that are not worth backing up".to_string(),
+      rules: vec![
+        rule(CleanupCategory::Trash, None),
+        rule(CleanupCategory::Cache, None),
+        rule(CleanupCategory::ThumbnailOrphans, None),
+      ],
+      builtIn: true,
+    },
+  ]
+}
@@ -0,0 +1,352 @@
+/* sys lib */
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+/* services */
+use crate::services::profile_service::ProfileService;
+use crate::services::settings_service::{SettingsService, SettingsState};
+
+/* models */
+use crate::models::{
+  AppError, DataValue, ErrorCode, ResponseModel, ResponseStatus, ScheduleModel, ScheduleRunModel,
+  ScheduleStatusModel,
+};
+
+/* helpers */
+use chrono::Local;
+use serde_json::json;
+
+const UNIT_PREFIX: &str = "cleanux-schedule-";
+
+pub struct ScheduleService {
+  settings: SettingsState,
+}
+
+#[allow(non_snake_case)]
+impl ScheduleService {
+  pub fn new(settings: SettingsState) -> Self {
+    Self { settings }
+  }
+
+  pub fn getSchedules(&self) -> Result<ResponseModel, AppError> {
+    let statuses: Vec<ScheduleStatusModel> = self
+      .settings
+      .get()
+      .schedules
+      .into_iter()
+      .map(|schedule| self.getScheduleStatus(schedule))
+      .collect();
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Found {} schedules", statuses.len()),
+      data: DataValue::Array(
+        statuses
+          .into_iter()
+          .map(|s| serde_json::to_value(s).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
+  }
+
+  pub fn saveSchedule(&self, schedule: ScheduleModel) -> Result<ResponseModel, AppError> {
+    self.validateCalendar(&schedule.onCalendar)?;
+
+    let settings = SettingsService.prepareChange(&self.settings, |settings| {
+      match settings.schedules.iter_mut().find(|s| s.id == schedule.id) {
+        Some(existing) => *existing = schedule.clone(),
+        None => settings.schedules.push(schedule.clone()),
+      }
+      Ok(())
+    })?;
+    // The timer changes before the settings are stored, so a failure to install or remove
+    // it leaves the stored schedule matching its timer
+    let previous = self
+      .settings
+      .get()
+      .schedules
+      .into_iter()
+      .find(|s| s.id == schedule.id);
+    self.syncUnits(&schedule)?;
+    if let Err(error) = SettingsService.commitChange(&self.settings, settings) {
+      let _ = match previous {
+        Some(previous) => self.syncUnits(&previous),
+        None => self.removeUnits(&schedule.id),
+      };
+      return Err(error);
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Schedule {} saved", schedule.name),
+      data: DataValue::Object(
+        serde_json::to_value(self.getScheduleStatus(schedule)).unwrap_or(json!({})),
+      ),
+    })
+  }
+
+  pub fn removeSchedule(&self, id: &str) -> Result<ResponseModel, AppError> {
+    if !self.settings.get().schedules.iter().any(|s| s.id == id) {
+      return Err(AppError::notFound(format!("Schedule {} not found", id)));
+    }
+
+    self.removeUnits(id)?;
+    SettingsService.applyChange(&self.settings, |settings| {
+      settings.schedules.retain(|s| s.id != id);
+      Ok(())
+    })?;
+    let _ = fs::remove_file(self.lastRunPath(id)?);
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Schedule {} removed", id),
+      data: DataValue::String(id.to_string()),
+    })
+  }
+
+  pub fn runSchedule(&self, id: &str) -> Result<ResponseModel, AppError> {
+    let schedule = self
+      .settings
+      .get()
+      .schedules
+      .into_iter()
+      .find(|s| s.id == id)
+      .ok_or_else(|| AppError::notFound(format!("Schedule {} not found", id)))?;
+
+    let startedAt = Local::now();
+    let profileRun =
+      ProfileService::new(self.settings.clone()).executeProfile(&schedule.profileId, false)?;
+
+    let run = ScheduleRunModel {
+      scheduleId: schedule.id.clone(),
+      profileId: profileRun.profileId,
+      startedAt: startedAt.format("%Y-%m-%d %H:%M:%S").to_string(),
+      finishedAt: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+      success: profileRun.results.iter().all(|r| r.errors.is_empty()),
+      removedFiles: profileRun.removedFiles,
+      freedBytes: profileRun.freedBytes,
+      results: profileRun.results,
+    };
+
+    let lastRunPath = self.lastRunPath(id)?;
+    if let Some(parent) = lastRunPath.parent() {
+      fs::create_dir_all(parent)
+        .map_err(|e| AppError::io(format!("Failed to create {}", parent.display()), &e))?;
+    }
+    let content = serde_json::to_string_pretty(&run).unwrap_or_default();
+    fs::write(&lastRunPath, content)
+      .map_err(|e| AppError::io(format!("Failed to write {}", lastRunPath.display()), &e))?;
+
+    let message = format!(
+      "Schedule {} removed {} files",
+      schedule.name, run.removedFiles
+    );
+    let failed = run.results.iter().filter(|r| !r.errors.is_empty()).count();
+    let data = DataValue::Object(serde_json::to_value(run).unwrap_or(json!({})));
+    // An error, so `--run-schedule` exits non-zero and systemd records the run as failed
+    if failed > 0 {
+      return Err(
+        AppError::new(
+          ErrorCode::PartialFailure,
+          format!("{}; {} cleanup rules failed", message, failed),
+        )
+        .withData(data),
+      );
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message,
+      data,
+    })
+  }
+
+  fn getScheduleStatus(&self, schedule: ScheduleModel) -> ScheduleStatusModel {
+    let timerName = format!("{}{}.timer", UNIT_PREFIX, schedule.id);
+    let installed = user_unit_dir()
+      .map(|dir| dir.join(&timerName).exists())
+      .unwrap_or(false);
+
+    let mut timerActive = false;
+    let mut nextRun = None;
+    let mut lastTrigger = None;
+    if installed {
+      if let Ok(output) = Command::new("systemctl")
+        .args([
+          "--user",
+          "show",
+          &timerName,
+          "--property=ActiveState,NextElapseUSecRealtime,LastTriggerUSec",
+        ])
+        .output()
+      {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for line in stdout.lines() {
+          let Some((key, value)) = line.split_once('=') else {
+            continue;
+          };
+          let value = (!value.is_empty() && value != "n/a").then(|| value.to_string());
+          match key {
+            "ActiveState" => timerActive = value.as_deref() == Some("active"),
+            "NextElapseUSecRealtime" => nextRun = value,
+            "LastTriggerUSec" => lastTrigger = value,
+            _ => {}
+          }
+        }
+      }
+    }
+
+    let lastRun = self
+      .lastRunPath(&schedule.id)
+      .ok()
+      .and_then(|path| fs::read_to_string(path).ok())
+      .and_then(|content| serde_json::from_str(&content).ok());
+
+    ScheduleStatusModel {
+      schedule,
+      installed,
+      timerActive,
+      nextRun,
+      lastTrigger,
+      lastRun,
+    }
+  }
+
+  fn validateCalendar(&self, onCalendar: &str) -> Result<(), AppError> {
+    let output = Command::new("systemd-analyze")
+      .args(["calendar", onCalendar])
+      .output()
+      .map_err(|e| AppError::spawn("systemd-analyze", &e))?;
+    if !output.status.success() {
+      return Err(AppError::invalidInput(format!(
+        "Invalid calendar expression {}: {}",
+        onCalendar,
+        String::from_utf8_lossy(&output.stderr).trim()
+      )));
+    }
+
+    Ok(())
+  }
+
+  fn syncUnits(&self, schedule: &ScheduleModel) -> Result<(), AppError> {
+    if schedule.enabled {
+      self.installUnits(schedule)
+    } else {
+      self.removeUnits(&schedule.id)
+    }
+  }
+
+  fn installUnits(&self, schedule: &ScheduleModel) -> Result<(), AppError> {
+    let unitDir = user_unit_dir()?;
+    fs::create_dir_all(&unitDir)
+      .map_err(|e| AppError::io(format!("Failed to create {}", unitDir.display()), &e))?;
+    let exe = cleanux_exe()?;
+
+    let serviceUnit = format!(
+      "[Unit]\n\
+       Description=Cleanux scheduled cleanup: {name}\n\
+       \n\
+       [Service]\n\
+       Type=oneshot\n\
+       ExecStart=\"{exe}\" --run-schedule {id}\n\
+       Nice=10\n\
+       IOSchedulingClass=idle\n",
+      name = unit_escape(&schedule.name),
+      exe = unit_escape(&exe.display().to_string()),
+      id = schedule.id,
+    );
+    let timerUnit = format!(
+      "[Unit]\n\
+       Description=Cleanux schedule timer: {name}\n\
+       \n\
+       [Timer]\n\
+       OnCalendar={calendar}\n\
+       Persistent=true\n\
+       \n\
+       [Install]\n\
+       WantedBy=timers.target\n",
+      name = unit_escape(&schedule.name),
+      calendar = schedule.onCalendar,
+    );
+
+    let unitName = format!("{}{}", UNIT_PREFIX, schedule.id);
+    for (file, content) in [
+      (format!("{}.service", unitName), serviceUnit),
+      (format!("{}.timer", unitName), timerUnit),
+    ] {
+      let path = unitDir.join(file);
+      fs::write(&path, content)
+        .map_err(|e| AppError::io(format!("Failed to write {}", path.display()), &e))?;
+    }
+
+    systemctl_user(&["daemon-reload"])?;
+    systemctl_user(&["enable", "--now", &format!("{}.timer", unitName)])
+  }
+
+  fn removeUnits(&self, id: &str) -> Result<(), AppError> {
+    let unitDir = user_unit_dir()?;
+    let unitName = format!("{}{}", UNIT_PREFIX, id);
+    let timerPath = unitDir.join(format!("{}.timer", unitName));
+    let servicePath = unitDir.join(format!("{}.service", unitName));
+    if !timerPath.exists() && !servicePath.exists() {
+      return Ok(());
+    }
+
+    systemctl_user(&["disable", "--now", &format!("{}.timer", unitName)])?;
+    for path in [timerPath, servicePath] {
+      if let Err(e) = fs::remove_file(&path) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+          return Err(AppError::io(
+            format!("Failed to remove {}", path.display()),
+            &e,
+          ));
+        }
+      }
+    }
+    systemctl_user(&["daemon-reload"])
+  }
+
+  fn lastRunPath(&self, id: &str) -> Result<PathBuf, AppError> {
+    let dataDir = dirs::data_dir().ok_or_else(|| AppError::notFound("Data directory not found"))?;
+    Ok(dataDir.join(format!("cleanux/schedules/{}.last-run.json", id)))
+  }
+}
+
+/// The executable user units should start: the AppImage itself when running from one,
+/// since the binary inside is unpacked to a different path each time.
+pub fn cleanux_exe() -> Result<PathBuf, AppError> {
+  std::env::var_os("APPIMAGE")
+    .map(PathBuf::from)
+    .or_else(|| std::env::current_exe().ok())
+    .ok_or_else(|| AppError::internal("Failed to resolve the Cleanux executable"))
+}
+
+pub fn user_unit_dir() -> Result<PathBuf, AppError> {
+  let config_dir =
+    dirs::config_dir().ok_or_else(|| AppError::notFound("Config directory not found"))?;
+  Ok(config_dir.join("systemd/user"))
+}
+
+pub fn systemctl_user(args: &[&str]) -> Result<(), AppError> {
+  let output = Command::new("systemctl")
+    .arg("--user")
+    .args(args)
+    .output()
+    .map_err(|e| AppError::spawn("systemctl", &e))?;
+
+  if output.status.success() {
+    Ok(())
+  } else {
+    Err(AppError::command(
+      format!("systemctl --user {} failed", args.join(" ")),
+      &output,
+    ))
+  }
+}
+
+/// Escapes `%` so systemd does not expand it as a specifier. Newlines and other control
+/// characters, which would start new directives, are rejected when settings are validated.
+pub fn unit_escape(value: &str) -> String {
+  value.replace('%', "%%")
+}
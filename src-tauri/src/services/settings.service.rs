@@ -0,0 +1,410 @@
+/* sys lib */
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/* services */
+use crate::services::monitor_service::MonitorService;
+use crate::services::profile_service::builtin_profiles;
+
+/* models */
+use crate::models::settings_model::{expand_home, SETTINGS_VERSION};
+use crate::models::{
+  AppError, DataValue, ResponseModel, ResponseStatus, SettingsErrorModel, SettingsModel,
+};
+
+/* helpers */
+use serde_json::{json, Value};
+
+#[derive(Clone)]
+pub struct SettingsState(Arc<RwLock<SettingsModel>>);
+
+impl SettingsState {
+  pub fn new(settings: SettingsModel) -> Self {
+    Self(Arc::new(RwLock::new(settings)))
+  }
+
+  pub fn get(&self) -> SettingsModel {
+    match self.0.read() {
+      Ok(settings) => settings.clone(),
+      Err(poisoned) => poisoned.into_inner().clone(),
+    }
+  }
+
+  pub fn set(&self, settings: SettingsModel) {
+    match self.0.write() {
+      Ok(mut current) => *current = settings,
+      Err(poisoned) => *poisoned.into_inner() = settings,
+    }
+  }
+}
+
+pub struct SettingsService;
+
+#[allow(non_snake_case)]
+impl SettingsService {
+  pub fn getSettings(&self, state: &SettingsState) -> Result<ResponseModel, AppError> {
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: "Settings retrieved successfully".to_string(),
+      data: DataValue::Object(serde_json::to_value(state.get()).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn updateSettings(
+    &self,
+    state: &SettingsState,
+    mut settings: SettingsModel,
+  ) -> Result<ResponseModel, AppError> {
+    // Profiles and schedules have their own commands, which also manage timer units
+    let current = state.get();
+    settings.profiles = current.profiles;
+    settings.schedules = current.schedules;
+
+    let settings = self.applyChange(state, |current| {
+      *current = settings;
+      Ok(())
+    })?;
+    // The settings are saved either way; only the background monitor's unit may lag behind
+    let synced = MonitorService::new(state.clone()).syncMonitorUnit();
+
+    Ok(ResponseModel {
+      status: if synced.is_ok() {
+        ResponseStatus::Success
+      } else {
+        ResponseStatus::Warning
+      },
+      message: match synced {
+        Ok(()) => "Settings saved".to_string(),
+        Err(error) => format!(
+          "Settings saved, but the background monitor was not updated: {}",
+          error.message
+        ),
+      },
+      data: DataValue::Object(serde_json::to_value(settings).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn applyChange<F>(&self, state: &SettingsState, change: F) -> Result<SettingsModel, AppError>
+  where
+    F: FnOnce(&mut SettingsModel) -> Result<(), AppError>,
+  {
+    let settings = self.prepareChange(state, change)?;
+    self.commitChange(state, settings)
+  }
+
+  /// Applies a change to a copy of the settings and validates it without saving, for
+  /// callers that must do something else before the change is stored.
+  pub fn prepareChange<F>(
+    &self,
+    state: &SettingsState,
+    change: F,
+  ) -> Result<SettingsModel, AppError>
+  where
+    F: FnOnce(&mut SettingsModel) -> Result<(), AppError>,
+  {
+    let mut settings = state.get();
+    change(&mut settings)?;
+    settings.version = SETTINGS_VERSION;
+
+    let errors = self.validateSettings(&settings);
+    if !errors.is_empty() {
+      return Err(
+        AppError::invalidInput(format!("Settings contain {} invalid values", errors.len()))
+          .withData(DataValue::Array(
+            errors
+              .into_iter()
+              .map(|e| serde_json::to_value(e).unwrap_or(json!({})))
+              .collect(),
+          )),
+      );
+    }
+
+    Ok(settings)
+  }
+
+  pub fn commitChange(
+    &self,
+    state: &SettingsState,
+    settings: SettingsModel,
+  ) -> Result<SettingsModel, AppError> {
+    self.storeSettings(&settings)?;
+    state.set(settings.clone());
+    Ok(settings)
+  }
+
+  pub fn loadSettings(&self) -> Result<SettingsModel, AppError> {
+    let path = self.settingsPath()?;
+    let (value, version) = match fs::read_to_string(&path) {
+      Ok(content) => {
+        let value: Value = serde_json::from_str(&content).map_err(|e| {
+          AppError::invalidInput(format!("Failed to parse {}: {}", path.display(), e))
+        })?;
+        let version = value.get("version").and_then(Value::as_u64).unwrap_or(0) as u32;
+        (value, version)
+      }
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => (json!({}), 0),
+      Err(e) => {
+        return Err(AppError::io(
+          format!("Failed to read {}", path.display()),
+          &e,
+        ))
+      }
+    };
+
+    if !value.is_object() {
+      return Err(AppError::invalidInput(format!(
+        "{} does not contain a settings object",
+        path.display()
+      )));
+    }
+    if version > SETTINGS_VERSION {
+      return Err(AppError::invalidInput(format!(
+        "{} was written by a newer Cleanux (settings version {})",
+        path.display(),
+        version
+      )));
+    }
+
+    let mut settings: SettingsModel = serde_json::from_value(value)
+      .map_err(|e| AppError::invalidInput(format!("Failed to parse {}: {}", path.display(), e)))?;
+
+    // Older formats need no migration yet; the file is only stamped with the current version
+    if version < SETTINGS_VERSION {
+      settings.version = SETTINGS_VERSION;
+      self.storeSettings(&settings)?;
+    }
+
+    Ok(settings)
+  }
+
+  fn validateSettings(&self, settings: &SettingsModel) -> Vec<SettingsErrorModel> {
+    let mut errors = Vec::new();
+    let mut check = |valid: bool, field: &str, message: &str| {
+      if !valid {
+        errors.push(SettingsErrorModel {
+          field: field.to_string(),
+          message: message.to_string(),
+        });
+      }
+    };
+
+    check(
+      settings.cache.maxDepth > 0,
+      "cache.maxDepth",
+      "Must be at least 1",
+    );
+    check(
+      settings.cache.listLimit > 0,
+      "cache.listLimit",
+      "Must be at least 1",
+    );
+    check(
+      settings.cache.summaryLimit > 0,
+      "cache.summaryLimit",
+      "Must be at least 1",
+    );
+    check(
+      settings
+        .cache
+        .cacheDir
+        .as_deref()
+        .is_none_or(|dir| home_relative(dir).is_some_and(|rest| !rest.as_os_str().is_empty())),
+      "cache.cacheDir",
+      "Must be a folder inside the home directory, such as ~/.cache",
+    );
+
+    check(
+      Path::new(&settings.logs.logDir).is_absolute(),
+      "logs.logDir",
+      "Must be an absolute path",
+    );
+    check(
+      settings.logs.maxDepth > 0,
+      "logs.maxDepth",
+      "Must be at least 1",
+    );
+    check(
+      settings.logs.summaryMaxDepth > 0,
+      "logs.summaryMaxDepth",
+      "Must be at least 1",
+    );
+    check(
+      settings.logs.listLimit > 0,
+      "logs.listLimit",
+      "Must be at least 1",
+    );
+    check(
+      settings.logs.analysisMaxBytes >= 1024 * 1024,
+      "logs.analysisMaxBytes",
+      "Must be at least 1 MB",
+    );
+
+    check(
+      settings.largeFiles.thresholdBytes >= 1024 * 1024,
+      "largeFiles.thresholdBytes",
+      "Must be at least 1 MB",
+    );
+    check(
+      !settings.largeFiles.roots.is_empty(),
+      "largeFiles.roots",
+      "At least one folder is required",
+    );
+    check(
+      settings
+        .largeFiles
+        .roots
+        .iter()
+        .all(|root| home_relative(root).is_some()),
+      "largeFiles.roots",
+      "Folders must be the home directory or inside it",
+    );
+    check(
+      settings.largeFiles.maxDepth > 0,
+      "largeFiles.maxDepth",
+      "Must be at least 1",
+    );
+    check(
+      settings.largeFiles.perRootLimit > 0,
+      "largeFiles.perRootLimit",
+      "Must be at least 1",
+    );
+    check(
+      settings.largeFiles.listLimit > 0,
+      "largeFiles.listLimit",
+      "Must be at least 1",
+    );
+
+    check(
+      (1..100).contains(&settings.monitor.minFreePercent),
+      "monitor.minFreePercent",
+      "Must be between 1 and 99 percent",
+    );
+    check(
+      settings.monitor.intervalMinutes > 0,
+      "monitor.intervalMinutes",
+      "Must be at least one minute",
+    );
+
+    let builtinIds: Vec<String> = builtin_profiles().into_iter().map(|p| p.id).collect();
+    let profileIds: Vec<&String> = builtinIds
+      .iter()
+      .chain(settings.profiles.iter().map(|p| &p.id))
+      .collect();
+    check(
+      settings
+        .monitor
+        .profileId
+        .as_ref()
+        .is_none_or(|id| profileIds.contains(&id)),
+      "monitor.profileId",
+      "Unknown cleanup profile",
+    );
+
+    for (index, profile) in settings.profiles.iter().enumerate() {
+      let field = format!("profiles[{}]", index);
+      check(
+        is_valid_id(&profile.id),
+        &format!("{}.id", field),
+        "May only contain lowercase letters, digits and dashes",
+      );
+      check(
+        !builtinIds.contains(&profile.id),
+        &format!("{}.id", field),
+        "Conflicts with a built-in profile",
+      );
+      check(
+        settings.profiles[..index]
+          .iter()
+          .all(|p| p.id != profile.id),
+        &format!("{}.id", field),
+        "Profile ids must be unique",
+      );
+      check(
+        !profile.name.trim().is_empty(),
+        &format!("{}.name", field),
+        "Name is required",
+      );
+      check(
+        !profile.rules.is_empty(),
+        &format!("{}.rules", field),
+        "At least one cleanup rule is required",
+      );
+    }
+
+    for (index, schedule) in settings.schedules.iter().enumerate() {
+      let field = format!("schedules[{}]", index);
+      check(
+        is_valid_id(&schedule.id),
+        &format!("{}.id", field),
+        "May only contain lowercase letters, digits and dashes",
+      );
+      check(
+        settings.schedules[..index]
+          .iter()
+          .all(|s| s.id != schedule.id),
+        &format!("{}.id", field),
+        "Schedule ids must be unique",
+      );
+      check(
+        !schedule.name.trim().is_empty() && !schedule.name.chars().any(char::is_control),
+        &format!("{}.name", field),
+        "Name is required and may not contain line breaks or control characters",
+      );
+      check(
+        !schedule.onCalendar.chars().any(char::is_control),
+        &format!("{}.onCalendar", field),
+        "May not contain line breaks or control characters",
+      );
+      check(
+        profileIds.contains(&&schedule.profileId),
+        &format!("{}.profileId", field),
+        "Unknown cleanup profile",
+      );
+    }
+
+    errors
+  }
+
+  fn storeSettings(&self, settings: &SettingsModel) -> Result<(), AppError> {
+    let path = self.settingsPath()?;
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)
+        .map_err(|e| AppError::io(format!("Failed to create {}", parent.display()), &e))?;
+    }
+
+    // Write to a sibling file first so a crash never leaves a truncated config
+    let tmpPath = path.with_extension("json.tmp");
+    let content = serde_json::to_string_pretty(settings).unwrap_or_default();
+    fs::write(&tmpPath, content)
+      .map_err(|e| AppError::io(format!("Failed to write {}", tmpPath.display()), &e))?;
+    fs::rename(&tmpPath, &path)
+      .map_err(|e| AppError::io(format!("Failed to replace {}", path.display()), &e))?;
+    Ok(())
+  }
+
+  fn settingsPath(&self) -> Result<PathBuf, AppError> {
+    let configDir =
+      dirs::config_dir().ok_or_else(|| AppError::notFound("Config directory not found"))?;
+    Ok(configDir.join("cleanux/settings.json"))
+  }
+}
+
+fn is_valid_id(id: &str) -> bool {
+  !id.is_empty()
+    && id
+      .chars()
+      .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// The part of an absolute or `~/` path below the home directory, empty for the home
+/// directory itself. Paths outside it, or with `..` that could climb back out, give `None`.
+/// Cleaning removes files under these folders, so `/` or another user's home never qualify.
+fn home_relative(path: &str) -> Option<PathBuf> {
+  let path = expand_home(path)?;
+  let rest = path.strip_prefix(dirs::home_dir()?).ok()?;
+  rest
+    .components()
+    .all(|component| matches!(component, Component::Normal(_)))
+    .then(|| rest.to_path_buf())
+}
@@ -0,0 +1,441 @@
+/* sys lib */
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/* services */
+use crate::services::system_service::run_unit_batch;
+use crate::services::systemd_service::SystemdService;
+
+/* models */
+use crate::models::{
+  AppError, BatchResultModel, DataValue, ResponseModel, ResponseStatus, ServiceAction,
+  ServiceScope, ServiceSnapshotModel, SnapshotRestoreModel, SystemServiceModel,
+  UnitStateChangeModel, UnitStateModel,
+};
+
+/* helpers */
+use chrono::Local;
+use serde_json::json;
+
+const SNAPSHOT_PATTERNS: [&str; 2] = ["*.service", "*.timer"];
+
+pub struct SnapshotService;
+
+#[allow(non_snake_case)]
+impl SnapshotService {
+  pub fn getServiceSnapshots(&self) -> Result<ResponseModel, AppError> {
+    let snapshotDir = self.snapshotDir()?;
+    let mut snapshots: Vec<ServiceSnapshotModel> = match fs::read_dir(&snapshotDir) {
+      Ok(entries) => entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .filter_map(|content| serde_json::from_str(&content).ok())
+        .collect(),
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+      Err(e) => {
+        return Err(AppError::io(
+          format!("Failed to read {}", snapshotDir.display()),
+          &e,
+        ))
+      }
+    };
+    snapshots.sort_by(|a, b| b.createdAt.cmp(&a.createdAt));
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Found {} service snapshots", snapshots.len()),
+      data: DataValue::Array(
+        snapshots
+          .into_iter()
+          .map(|s| serde_json::to_value(s).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
+  }
+
+  pub fn createServiceSnapshot(
+    &self,
+    name: &str,
+    scope: ServiceScope,
+  ) -> Result<ResponseModel, AppError> {
+    if name.trim().is_empty() {
+      return Err(AppError::invalidInput("A snapshot name is required"));
+    }
+
+    let systemd = SystemdService::connect(scope)?;
+    let now = Local::now();
+    let baseId = now.format("%Y%m%d-%H%M%S").to_string();
+    let mut id = baseId.clone();
+    let mut suffix = 1;
+    while self.snapshotPath(&id)?.exists() {
+      suffix += 1;
+      id = format!("{}-{}", baseId, suffix);
+    }
+
+    let snapshot = ServiceSnapshotModel {
+      id,
+      name: name.trim().to_string(),
+      scope,
+      createdAt: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+      units: capture_states(&systemd)?,
+    };
+    self.storeSnapshot(&snapshot)?;
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!(
+        "Saved the state of {} units as {}",
+        snapshot.units.len(),
+        snapshot.name
+      ),
+      data: DataValue::Object(serde_json::to_value(snapshot).unwrap_or(json!({}))),
+    })
+  }
+
+  pub fn removeServiceSnapshot(&self, id: &str) -> Result<ResponseModel, AppError> {
+    let path = self.snapshotPath(id)?;
+    fs::remove_file(&path).map_err(|e| match e.kind() {
+      std::io::ErrorKind::NotFound => AppError::notFound(format!("Snapshot {} not found", id)),
+      _ => AppError::io(format!("Failed to remove {}", path.display()), &e),
+    })?;
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Snapshot {} removed", id),
+      data: DataValue::String(id.to_string()),
+    })
+  }
+
+  pub fn diffServiceSnapshot(&self, id: &str) -> Result<ResponseModel, AppError> {
+    let snapshot = self.readSnapshot(id)?;
+    let systemd = SystemdService::connect(snapshot.scope)?;
+    let changes = diff_states(&snapshot.units, &capture_states(&systemd)?);
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("{} units differ from {}", changes.len(), snapshot.name),
+      data: DataValue::Array(
+        changes
+          .into_iter()
+          .map(|c| serde_json::to_value(c).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
+  }
+
+  pub fn restoreServiceSnapshot(&self, id: &str) -> Result<ResponseModel, AppError> {
+    let snapshot = self.readSnapshot(id)?;
+    let systemd = SystemdService::connect(snapshot.scope)?;
+    let changes = diff_states(&snapshot.units, &capture_states(&systemd)?);
+    if changes.is_empty() {
+      return Ok(ResponseModel {
+        status: ResponseStatus::Success,
+        message: format!("Units already match {}", snapshot.name),
+        data: DataValue::Array(vec![]),
+      });
+    }
+
+    let mut outcomes: HashMap<String, Result<(), AppError>> = HashMap::new();
+    let mut record = |name: &str, result: Result<(), AppError>| {
+      let outcome = outcomes.entry(name.to_string()).or_insert(Ok(()));
+      if outcome.is_ok() {
+        *outcome = result;
+      }
+    };
+
+    // Unit file changes are batched into one call per action, since each reloads systemd
+    let actions = [
+      ServiceAction::Unmask,
+      ServiceAction::Disable,
+      ServiceAction::Enable,
+      ServiceAction::Mask,
+    ];
+    for action in actions {
+      let units: Vec<String> = changes
+        .iter()
+        .filter(|change| unit_file_actions(change).contains(&action))
+        .map(|change| change.name.clone())
+        .collect();
+      if units.is_empty() {
+        continue;
+      }
+      for item in run_unit_batch(&systemd, action, &units).items {
+        let unit = item.item.clone();
+        record(&unit, item.toResult());
+      }
+    }
+
+    let mut stops = Vec::new();
+    let mut starts = Vec::new();
+    for change in &changes {
+      let Some(current) = &change.current else {
+        record(
+          &change.name,
+          Err(AppError::notFound(format!(
+            "{} no longer exists",
+            change.name
+          ))),
+        );
+        continue;
+      };
+      match (
+        is_active(&change.snapshot.active),
+        is_active(&current.active),
+      ) {
+        (true, false) => starts.push(change.name.clone()),
+        (false, true) => stops.push(change.name.clone()),
+        _ => {}
+      }
+    }
+    for (action, units) in [(ServiceAction::Stop, stops), (ServiceAction::Start, starts)] {
+      if units.is_empty() {
+        continue;
+      }
+      for item in run_unit_batch(&systemd, action, &units).items {
+        let unit = item.item.clone();
+        record(&unit, item.toResult());
+      }
+    }
+
+    let mut batch = BatchResultModel::default();
+    for change in &changes {
+      batch.record(
+        &change.name,
+        outcomes.remove(&change.name).unwrap_or(Ok(())),
+      );
+    }
+
+    let error = batch.toError("Units restored:");
+    let succeeded = batch.succeeded;
+    let result = SnapshotRestoreModel {
+      batch,
+      changes: diff_states(&snapshot.units, &capture_states(&systemd)?),
+    };
+    let data = DataValue::Object(serde_json::to_value(result).unwrap_or(json!({})));
+    if let Some(error) = error {
+      return Err(error.withData(data));
+    }
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Restored {} units to {}", succeeded, snapshot.name),
+      data,
+    })
+  }
+
+  fn readSnapshot(&self, id: &str) -> Result<ServiceSnapshotModel, AppError> {
+    let path = self.snapshotPath(id)?;
+    let content = fs::read_to_string(&path).map_err(|e| match e.kind() {
+      std::io::ErrorKind::NotFound => AppError::notFound(format!("Snapshot {} not found", id)),
+      _ => AppError::io(format!("Failed to read {}", path.display()), &e),
+    })?;
+    serde_json::from_str(&content)
+      .map_err(|e| AppError::invalidInput(format!("Failed to parse {}: {}", path.display(), e)))
+  }
+
+  fn storeSnapshot(&self, snapshot: &ServiceSnapshotModel) -> Result<(), AppError> {
+    let snapshotDir = self.snapshotDir()?;
+    fs::create_dir_all(&snapshotDir)
+      .map_err(|e| AppError::io(format!("Failed to create {}", snapshotDir.display()), &e))?;
+
+    let path = self.snapshotPath(&snapshot.id)?;
+    let content = serde_json::to_string_pretty(snapshot).unwrap_or_default();
+    fs::write(&path, content)
+      .map_err(|e| AppError::io(format!("Failed to write {}", path.display()), &e))
+  }
+
+  fn snapshotPath(&self, id: &str) -> Result<PathBuf, AppError> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit() || c == '-') {
+      return Err(AppError::invalidInput(format!(
+        "Invalid snapshot id: {}",
+        id
+      )));
+    }
+    Ok(self.snapshotDir()?.join(format!("{}.json", id)))
+  }
+
+  fn snapshotDir(&self) -> Result<PathBuf, AppError> {
+    let dataDir = dirs::data_dir().ok_or_else(|| AppError::notFound("Data directory not found"))?;
+    Ok(dataDir.join("cleanux/snapshots"))
+  }
+}
+
+fn capture_states(systemd: &SystemdService) -> Result<Vec<UnitStateModel>, AppError> {
+  let loaded = systemd.listUnits(&[], &SNAPSHOT_PATTERNS)?;
+  let files = systemd.listUnitFiles(&[], &SNAPSHOT_PATTERNS)?;
+  Ok(merge_states(loaded, files, |name| {
+    systemd.getUnitFileState(name).unwrap_or_default()
+  }))
+}
+
+/// Unit files with the active state of their loaded unit, plus loaded template instances
+/// such as `getty@tty1.service`, which have no unit file of their own. Templates have no
+/// state and are left out.
+fn merge_states(
+  loaded: Vec<SystemServiceModel>,
+  files: Vec<(String, String)>,
+  file_state: impl Fn(&str) -> String,
+) -> Vec<UnitStateModel> {
+  let mut active: HashMap<String, SystemServiceModel> = loaded
+    .into_iter()
+    .map(|unit| (unit.name.clone(), unit))
+    .collect();
+
+  let mut units: Vec<UnitStateModel> = files
+    .into_iter()
+    .filter(|(name, _)| !name.contains("@."))
+    .map(|(name, state)| UnitStateModel {
+      active: active
+        .remove(&name)
+        .map_or_else(|| "inactive".to_string(), |unit| unit.active),
+      name,
+      unitFileState: state,
+    })
+    .collect();
+  units.extend(
+    active
+      .into_values()
+      .filter(|unit| unit.name.contains('@') && unit.load == "loaded")
+      .map(|unit| UnitStateModel {
+        unitFileState: file_state(&unit.name),
+        name: unit.name,
+        active: unit.active,
+      }),
+  );
+  units.sort_by(|a, b| a.name.cmp(&b.name));
+  units.dedup_by(|a, b| a.name == b.name);
+  units
+}
+
+fn diff_states(
+  snapshot: &[UnitStateModel],
+  current: &[UnitStateModel],
+) -> Vec<UnitStateChangeModel> {
+  let current: HashMap<&str, &UnitStateModel> = current
+    .iter()
+    .map(|unit| (unit.name.as_str(), unit))
+    .collect();
+
+  snapshot
+    .iter()
+    .filter_map(|unit| {
+      let now = current.get(unit.name.as_str()).copied();
+      let unchanged = now.is_some_and(|now| {
+        now.unitFileState == unit.unitFileState && is_active(&now.active) == is_active(&unit.active)
+      });
+      if unchanged {
+        return None;
+      }
+      Some(UnitStateChangeModel {
+        name: unit.name.clone(),
+        snapshot: unit.clone(),
+        current: now.cloned(),
+      })
+    })
+    .collect()
+}
+
+/// Unit file operations that bring a unit back to its snapshot state. States other than
+/// enabled, disabled and masked (static, generated, ...) cannot be changed.
+fn unit_file_actions(change: &UnitStateChangeModel) -> Vec<ServiceAction> {
+  let Some(current) = &change.current else {
+    return Vec::new();
+  };
+  let (from, to) = (
+    current.unitFileState.as_str(),
+    change.snapshot.unitFileState.as_str(),
+  );
+  if from == to {
+    return Vec::new();
+  }
+
+  let mut actions = Vec::new();
+  if from == "masked" {
+    actions.push(ServiceAction::Unmask);
+  }
+  match to {
+    "enabled" => actions.push(ServiceAction::Enable),
+    "masked" => actions.push(ServiceAction::Mask),
+    "disabled" if from == "enabled" => actions.push(ServiceAction::Disable),
+    _ => {}
+  }
+  actions
+}
+
+fn is_active(state: &str) -> bool {
+  matches!(state, "active" | "activating" | "reloading")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn loaded(name: &str, active: &str) -> SystemServiceModel {
+    SystemServiceModel {
+      name: name.to_string(),
+      scope: ServiceScope::System,
+      description: String::new(),
+      load: "loaded".to_string(),
+      active: active.to_string(),
+      status: String::new(),
+      isRunning: active == "active",
+    }
+  }
+
+  #[test]
+  fn merge_states_keeps_unit_files_and_their_active_state() {
+    let units = merge_states(
+      vec![loaded("sshd.service", "active")],
+      vec![
+        ("sshd.service".to_string(), "enabled".to_string()),
+        ("cups.service".to_string(), "disabled".to_string()),
+      ],
+      |_| panic!("unit files have their own state"),
+    );
+
+    let states: Vec<(&str, &str, &str)> = units
+      .iter()
+      .map(|u| (u.name.as_str(), u.unitFileState.as_str(), u.active.as_str()))
+      .collect();
+    assert_eq!(
+      states,
+      [
+        ("cups.service", "disabled", "inactive"),
+        ("sshd.service", "enabled", "active"),
+      ]
+    );
+  }
+
+  #[test]
+  fn merge_states_adds_loaded_template_instances() {
+    let units = merge_states(
+      vec![
+        loaded("getty@tty1.service", "active"),
+        loaded("user@1000.service", "active"),
+        loaded("run-u42.service", "active"),
+      ],
+      vec![
+        ("getty@.service".to_string(), "enabled".to_string()),
+        ("user@.service".to_string(), "static".to_string()),
+      ],
+      |name| match name {
+        "getty@tty1.service" => "enabled".to_string(),
+        _ => "static".to_string(),
+      },
+    );
+
+    let states: Vec<(&str, &str, &str)> = units
+      .iter()
+      .map(|u| (u.name.as_str(), u.unitFileState.as_str(), u.active.as_str()))
+      .collect();
+    assert_eq!(
+      states,
+      [
+        ("getty@tty1.service", "enabled", "active"),
+        ("user@1000.service", "static", "active"),
+      ]
+    );
+  }
+}
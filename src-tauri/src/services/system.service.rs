@@ -1,85 +1,21 @@
-/* sys lib */
-use std::process::Command;
+/* services */
+use crate::services::helper_service::HelperService;
+use crate::services::systemd_service::SystemdService;
 
 /* models */
-use crate::models::{DataValue, ResponseModel, ResponseStatus};
+use crate::models::{
+  AppError, BatchResultModel, DataValue, ResponseModel, ResponseStatus, ServiceAction,
+  ServiceBatchResultModel, ServiceScope,
+};
+
+/* helpers */
+use serde_json::json;
 
 pub struct SystemService;
 
 #[allow(non_snake_case)]
 impl SystemService {
-  pub fn stopService(&self, service: &str) -> Result<ResponseModel, ResponseModel> {
-    let output = Command::new("pkexec")
-      .args(["systemctl", "stop", service])
-      .output()
-      .map_err(|e| format!("Failed to run command: {}", e))?;
-    if output.status.success() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Service {} stopped", service),
-        data: DataValue::String(service.to_string()),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: String::from_utf8_lossy(&output.stderr).to_string(),
-        data: DataValue::String("".to_string()),
-      })
-    }
-  }
-
-  pub fn stopSelectedServices(
-    &self,
-    services: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
-    if services.is_empty() {
-      return Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: "No services selected".to_string(),
-        data: DataValue::Array(vec![]),
-      });
-    }
-
-    let mut cmd = std::process::Command::new("pkexec");
-    cmd.arg("systemctl").arg("stop");
-    for service in &services {
-      cmd.arg(service);
-    }
-
-    let output = cmd.output().map_err(|e| ResponseModel {
-      status: ResponseStatus::Error,
-      message: format!("Failed to run pkexec: {}", e),
-      data: DataValue::Array(vec![]),
-    })?;
-
-    if output.status.success() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Stopped {} services successfully", services.len()),
-        data: DataValue::Array(
-          services
-            .into_iter()
-            .map(|s| serde_json::Value::String(s))
-            .collect(),
-        ),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!(
-          "Failed to stop services: {}",
-          String::from_utf8_lossy(&output.stderr).trim()
-        ),
-        data: DataValue::Array(vec![]),
-      })
-    }
-  }
-
-  pub fn openFile(
-    &self,
-    path: &str,
-    command: Option<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
+  pub fn openFile(&self, path: &str, command: Option<String>) -> Result<ResponseModel, AppError> {
     let mut cmd = if let Some(custom_cmd) = command {
       let mut c = std::process::Command::new(custom_cmd);
       c.arg(path);
@@ -96,56 +32,16 @@ impl SystemService {
         message: format!("Started editor for file: {}", path),
         data: DataValue::String(path.to_string()),
       }),
-      Err(e) => Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!("Failed to start editor: {}", e),
-        data: DataValue::String("".to_string()),
-      }),
+      Err(e) => Err(AppError::io("Failed to start editor", &e)),
     }
   }
 
   #[allow(dead_code)]
-  pub fn getDisabledServices(&self) -> Result<ResponseModel, ResponseModel> {
-    let output = Command::new("systemctl")
-      .args([
-        "list-unit-files",
-        "--state=enabled",
-        "--no-pager",
-        "--no-legend",
-      ])
-      .output()
-      .map_err(|e| ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!("Failed to list services: {}", e),
-        data: DataValue::Array(vec![]),
-      })?;
-
-    if !output.status.success() {
-      return Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: String::from_utf8_lossy(&output.stderr).to_string(),
-        data: DataValue::Array(vec![]),
-      });
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let services: Vec<serde_json::Value> = stdout
-      .lines()
-      .filter_map(|line| {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.is_empty() {
-          return None;
-        }
-        let name = parts[0].to_string();
-        if name.ends_with(".service") {
-          Some(serde_json::json!({
-            "name": name,
-            "status": "enabled"
-          }))
-        } else {
-          None
-        }
-      })
+  pub fn getDisabledServices(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    let services: Vec<serde_json::Value> = SystemdService::connect(scope)?
+      .listUnitFiles(&["enabled"], &["*.service"])?
+      .into_iter()
+      .map(|(name, status)| json!({ "name": name, "status": status }))
       .collect();
 
     Ok(ResponseModel {
@@ -155,115 +51,212 @@ impl SystemService {
     })
   }
 
-  pub fn getAllServices(&self) -> Result<ResponseModel, ResponseModel> {
-    let output = Command::new("systemctl")
-      .args([
-        "list-units",
-        "--type=service",
-        "--all",
-        "--no-pager",
-        "--no-legend",
-      ])
-      .output()
-      .map_err(|e| ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!("Failed to list services: {}", e),
-        data: DataValue::Array(vec![]),
-      })?;
+  pub fn getAllServices(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    let services = SystemdService::connect(scope)?.listServices(&[])?;
 
-    if !output.status.success() {
-      return Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: String::from_utf8_lossy(&output.stderr).to_string(),
-        data: DataValue::Array(vec![]),
-      });
-    }
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Found {} services", services.len()),
+      data: DataValue::Array(
+        services
+          .into_iter()
+          .map(|s| serde_json::to_value(s).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
+  }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let services: Vec<serde_json::Value> = stdout
-      .lines()
-      .filter_map(|line| {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() >= 4 {
-          let name = parts[0].to_string();
-          let load = parts[1].to_string();
-          let active = parts[2].to_string();
-          let status = parts[3].to_string();
-          if name.ends_with(".service") {
-            return Some(serde_json::json!({
-              "name": name,
-              "load": load,
-              "active": active,
-              "status": status,
-              "isRunning": active == "active"
-            }));
-          }
-        }
-        None
-      })
-      .collect();
+  pub fn getServiceDetails(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    let details = SystemdService::connect(scope)?.getUnitDetails(service)?;
 
     Ok(ResponseModel {
       status: ResponseStatus::Success,
-      message: format!("Found {} services", services.len()),
-      data: DataValue::Array(services),
+      message: format!("Details of {} retrieved successfully", service),
+      data: DataValue::Object(serde_json::to_value(details).unwrap_or(json!({}))),
     })
   }
 
-  pub fn enableService(&self, service: &str) -> Result<ResponseModel, ResponseModel> {
-    let output = Command::new("pkexec")
-      .args(["systemctl", "enable", service])
-      .output()
-      .map_err(|e| ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!("Failed to run command: {}", e),
-        data: DataValue::String("".to_string()),
-      })?;
-
-    if output.status.success() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Service {} enabled", service),
-        data: DataValue::String(service.to_string()),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: String::from_utf8_lossy(&output.stderr).to_string(),
-        data: DataValue::String("".to_string()),
-      })
-    }
+  pub fn getTimers(&self, scope: ServiceScope) -> Result<ResponseModel, AppError> {
+    let timers = SystemdService::connect(scope)?.listTimers()?;
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Found {} timers", timers.len()),
+      data: DataValue::Array(
+        timers
+          .into_iter()
+          .map(|t| serde_json::to_value(t).unwrap_or(json!({})))
+          .collect(),
+      ),
+    })
   }
 
-  pub fn startService(&self, service: &str) -> Result<ResponseModel, ResponseModel> {
-    let output = Command::new("pkexec")
-      .args(["systemctl", "start", service])
-      .output()
-      .map_err(|e| ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!("Failed to run command: {}", e),
-        data: DataValue::String("".to_string()),
-      })?;
-
-    if output.status.success() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Service {} started", service),
-        data: DataValue::String(service.to_string()),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: String::from_utf8_lossy(&output.stderr).to_string(),
-        data: DataValue::String("".to_string()),
-      })
-    }
+  pub fn startService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::Start, service)
+  }
+
+  pub fn stopService(&self, scope: ServiceScope, service: &str) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::Stop, service)
+  }
+
+  pub fn restartService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::Restart, service)
+  }
+
+  pub fn reloadService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::Reload, service)
+  }
+
+  pub fn enableService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::Enable, service)
+  }
+
+  pub fn disableService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::Disable, service)
+  }
+
+  pub fn maskService(&self, scope: ServiceScope, service: &str) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::Mask, service)
+  }
+
+  pub fn unmaskService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::Unmask, service)
+  }
+
+  pub fn resetFailedService(
+    &self,
+    scope: ServiceScope,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyAction(scope, ServiceAction::ResetFailed, service)
+  }
+
+  pub fn startSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::Start, services)
+  }
+
+  pub fn stopSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::Stop, services)
+  }
+
+  pub fn restartSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::Restart, services)
+  }
+
+  pub fn reloadSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::Reload, services)
   }
 
   pub fn enableSelectedServices(
     &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::Enable, services)
+  }
+
+  pub fn disableSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::Disable, services)
+  }
+
+  pub fn maskSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::Mask, services)
+  }
+
+  pub fn unmaskSelectedServices(
+    &self,
+    scope: ServiceScope,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::Unmask, services)
+  }
+
+  pub fn resetFailedSelectedServices(
+    &self,
+    scope: ServiceScope,
     services: Vec<String>,
-  ) -> Result<ResponseModel, ResponseModel> {
+  ) -> Result<ResponseModel, AppError> {
+    self.applyBatchAction(scope, ServiceAction::ResetFailed, services)
+  }
+
+  fn applyAction(
+    &self,
+    scope: ServiceScope,
+    action: ServiceAction,
+    service: &str,
+  ) -> Result<ResponseModel, AppError> {
+    let systemd = SystemdService::connect(scope)?;
+    let batch = run_unit_batch(&systemd, action, &[service.to_string()]);
+    if let Some(item) = batch.items.into_iter().next() {
+      item.toResult()?;
+    }
+    let unit = systemd.getUnit(service)?;
+
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("Service {} {}", service, action.pastTense()),
+      data: DataValue::Object(serde_json::to_value(unit).unwrap_or(json!({}))),
+    })
+  }
+
+  fn applyBatchAction(
+    &self,
+    scope: ServiceScope,
+    action: ServiceAction,
+    services: Vec<String>,
+  ) -> Result<ResponseModel, AppError> {
     if services.is_empty() {
       return Ok(ResponseModel {
         status: ResponseStatus::Success,
@@ -272,38 +265,95 @@ impl SystemService {
       });
     }
 
-    let mut cmd = std::process::Command::new("pkexec");
-    cmd.arg("systemctl").arg("enable");
-    for service in &services {
-      cmd.arg(service);
+    let systemd = SystemdService::connect(scope)?;
+    let batch = run_unit_batch(&systemd, action, &services);
+
+    let verb = action.pastTense();
+    let error = batch.toError(&format!("Services {}:", verb));
+    let succeeded = batch.succeeded;
+    let result = ServiceBatchResultModel {
+      batch,
+      units: services
+        .iter()
+        .filter_map(|service| systemd.getUnit(service).ok())
+        .collect(),
+    };
+    let data = DataValue::Object(serde_json::to_value(result).unwrap_or(json!({})));
+    if let Some(error) = error {
+      return Err(error.withData(data));
     }
 
-    let output = cmd.output().map_err(|e| ResponseModel {
-      status: ResponseStatus::Error,
-      message: format!("Failed to run pkexec: {}", e),
-      data: DataValue::Array(vec![]),
-    })?;
+    Ok(ResponseModel {
+      status: ResponseStatus::Success,
+      message: format!("{} services {} successfully", succeeded, verb),
+      data,
+    })
+  }
+}
 
-    if output.status.success() {
-      Ok(ResponseModel {
-        status: ResponseStatus::Success,
-        message: format!("Enabled {} services successfully", services.len()),
-        data: DataValue::Array(
-          services
-            .into_iter()
-            .map(|s| serde_json::Value::String(s))
-            .collect(),
-        ),
-      })
-    } else {
-      Err(ResponseModel {
-        status: ResponseStatus::Error,
-        message: format!(
-          "Failed to enable services: {}",
-          String::from_utf8_lossy(&output.stderr).trim()
-        ),
-        data: DataValue::Array(vec![]),
-      })
+/// Runs an action on units through the privileged helper while it has an elevated
+/// session open for this process, so no further authentication is asked for.
+pub fn run_unit_batch(
+  systemd: &SystemdService,
+  action: ServiceAction,
+  services: &[String],
+) -> BatchResultModel {
+  if systemd.scope() == ServiceScope::System {
+    if let Some(helper) = HelperService::activeSession() {
+      let mut batch = BatchResultModel::default();
+      match helper.manageUnits(action, services) {
+        Ok(items) => items.into_iter().for_each(|item| batch.push(item)),
+        Err(error) => {
+          for service in services {
+            batch.record(service, Err(error.clone()));
+          }
+        }
+      }
+      return batch;
+    }
+  }
+  run_batch_action(systemd, action, services)
+}
+
+pub fn run_batch_action(
+  systemd: &SystemdService,
+  action: ServiceAction,
+  services: &[String],
+) -> BatchResultModel {
+  let mut batch = BatchResultModel::default();
+  if action.changesUnitFiles() {
+    // One unit file call covers every unit, so they share its outcome
+    let result = run_action(systemd, action, services);
+    for service in services {
+      batch.record(service, result.clone());
     }
+  } else {
+    for service in services {
+      batch.record(
+        service,
+        run_action(systemd, action, std::slice::from_ref(service)),
+      );
+    }
+  }
+  batch
+}
+
+pub fn run_action(
+  systemd: &SystemdService,
+  action: ServiceAction,
+  services: &[String],
+) -> Result<(), AppError> {
+  match action {
+    ServiceAction::Enable => systemd.enableUnitFiles(services),
+    ServiceAction::Disable => systemd.disableUnitFiles(services),
+    ServiceAction::Mask => systemd.maskUnitFiles(services),
+    ServiceAction::Unmask => systemd.unmaskUnitFiles(services),
+    _ => services.iter().try_for_each(|service| match action {
+      ServiceAction::Start => systemd.startUnit(service),
+      ServiceAction::Stop => systemd.stopUnit(service),
+      ServiceAction::Restart => systemd.restartUnit(service),
+      ServiceAction::ResetFailed => systemd.resetFailedUnit(service),
+      _ => systemd.reloadUnit(service),
+    }),
   }
 }
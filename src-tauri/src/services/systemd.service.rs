@@ -0,0 +1,799 @@
+/* sys lib */
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/* models */
+use crate::models::{
+  AppError, ErrorCode, FailedUnitModel, ServiceDetailsModel, ServiceScope, SystemServiceModel,
+  TimerModel,
+};
+
+/* helpers */
+use chrono::{Local, TimeZone, Utc};
+use rustix::time::{clock_gettime, ClockId};
+use zbus::blocking::{Connection, Proxy};
+use zbus::proxy::MethodFlags;
+use zbus::zvariant::{OwnedObjectPath, OwnedValue};
+
+const SYSTEMD_DESTINATION: &str = "org.freedesktop.systemd1";
+const SYSTEMD_PATH: &str = "/org/freedesktop/systemd1";
+const MANAGER_INTERFACE: &str = "org.freedesktop.systemd1.Manager";
+const UNIT_INTERFACE: &str = "org.freedesktop.systemd1.Unit";
+const SERVICE_INTERFACE: &str = "org.freedesktop.systemd1.Service";
+const TIMER_INTERFACE: &str = "org.freedesktop.systemd1.Timer";
+const PROPERTIES_INTERFACE: &str = "org.freedesktop.DBus.Properties";
+
+/// How long to wait for a start, stop, restart or reload job; longer than systemd's own
+/// default start and stop timeouts of 90 seconds.
+const JOB_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Entry of `ListUnits`: name, description, load, active and sub state, followed unit,
+/// unit object path, job id, job type and job object path.
+type UnitRecord = (
+  String,
+  String,
+  String,
+  String,
+  String,
+  String,
+  OwnedObjectPath,
+  u32,
+  String,
+  OwnedObjectPath,
+);
+
+/// Change reported by `EnableUnitFiles`: change type, symlink path and its destination.
+type UnitFileChange = (String, String, String);
+
+#[allow(non_snake_case)]
+pub struct SystemdService {
+  connection: Connection,
+  scope: ServiceScope,
+  jobTimeout: Duration,
+}
+
+#[allow(non_snake_case)]
+impl SystemdService {
+  pub fn new(connection: Connection, scope: ServiceScope) -> Self {
+    Self {
+      connection,
+      scope,
+      jobTimeout: JOB_TIMEOUT,
+    }
+  }
+
+  pub fn scope(&self) -> ServiceScope {
+    self.scope
+  }
+
+  pub fn connect(scope: ServiceScope) -> Result<Self, AppError> {
+    // User units are managed by the per-user systemd instance on the session bus
+    let connection = match scope {
+      ServiceScope::System => Connection::system(),
+      ServiceScope::User => Connection::session(),
+    };
+    connection
+      .map(|connection| Self::new(connection, scope))
+      .map_err(|e| dbus_error("Failed to connect to systemd", e))
+  }
+
+  pub fn listServices(&self, states: &[&str]) -> Result<Vec<SystemServiceModel>, AppError> {
+    self.listUnits(states, &["*.service"])
+  }
+
+  pub fn listUnits(
+    &self,
+    states: &[&str],
+    patterns: &[&str],
+  ) -> Result<Vec<SystemServiceModel>, AppError> {
+    let units: Vec<UnitRecord> = self
+      .manager()?
+      .call("ListUnitsByPatterns", &(states, patterns))
+      .map_err(|e| dbus_error("Failed to list units", e))?;
+
+    let mut services: Vec<SystemServiceModel> = units
+      .into_iter()
+      .map(
+        |(name, description, load, active, sub, ..)| SystemServiceModel {
+          name,
+          scope: self.scope,
+          description,
+          load,
+          active,
+          isRunning: sub == "running",
+          status: sub,
+        },
+      )
+      .collect();
+    services.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(services)
+  }
+
+  pub fn listTimers(&self) -> Result<Vec<TimerModel>, AppError> {
+    self
+      .listUnits(&[], &["*.timer"])?
+      .into_iter()
+      .map(|timer| {
+        let path = self.loadUnit(&timer.name)?;
+        let mut unit = self.unitProperties(path.as_str(), UNIT_INTERFACE)?;
+        let mut properties = self.unitProperties(path.as_str(), TIMER_INTERFACE)?;
+        let mut elapse = |key: &str| {
+          take_property::<u64>(&mut properties, key).filter(|&usec| usec > 0 && usec != u64::MAX)
+        };
+
+        // Calendar timers elapse on the realtime clock, OnBootSec= style ones on the
+        // monotonic clock; the earlier of the two is when the timer fires next
+        let realtime = elapse("NextElapseUSecRealtime");
+        let monotonic = elapse("NextElapseUSecMonotonic").map(monotonic_to_realtime);
+        let next = match (realtime, monotonic) {
+          (Some(realtime), Some(monotonic)) => Some(realtime.min(monotonic)),
+          (realtime, monotonic) => realtime.or(monotonic),
+        };
+
+        Ok(TimerModel {
+          triggers: take_property(&mut properties, "Unit").unwrap_or_default(),
+          unitFileState: take_property(&mut unit, "UnitFileState").unwrap_or_default(),
+          nextElapse: next.and_then(format_timestamp),
+          lastTrigger: take_property(&mut properties, "LastTriggerUSec").and_then(format_timestamp),
+          unit: timer,
+        })
+      })
+      .collect()
+  }
+
+  pub fn listUnitFiles(
+    &self,
+    states: &[&str],
+    patterns: &[&str],
+  ) -> Result<Vec<(String, String)>, AppError> {
+    let files: Vec<(String, String)> = self
+      .manager()?
+      .call("ListUnitFilesByPatterns", &(states, patterns))
+      .map_err(|e| dbus_error("Failed to list unit files", e))?;
+
+    Ok(
+      files
+        .into_iter()
+        .filter_map(|(path, state)| {
+          let name = path.rsplit('/').next()?.to_string();
+          Some((name, state))
+        })
+        .collect(),
+    )
+  }
+
+  /// Enablement of one unit, which also works for template instances that
+  /// `listUnitFiles` does not report.
+  pub fn getUnitFileState(&self, name: &str) -> Result<String, AppError> {
+    self
+      .manager()?
+      .call("GetUnitFileState", &(name,))
+      .map_err(|e| dbus_error(format!("Failed to read the unit file state of {}", name), e))
+  }
+
+  pub fn getUnit(&self, name: &str) -> Result<SystemServiceModel, AppError> {
+    let path = self.loadUnit(name)?;
+    let mut properties = self.unitProperties(path.as_str(), UNIT_INTERFACE)?;
+    Ok(self.unitModel(&mut properties))
+  }
+
+  pub fn getUnitDetails(&self, name: &str) -> Result<ServiceDetailsModel, AppError> {
+    let path = self.loadUnit(name)?;
+    let mut unit = self.unitProperties(path.as_str(), UNIT_INTERFACE)?;
+    // Units that are not services (or not loaded) have no Service interface to read
+    let mut service = self
+      .unitProperties(path.as_str(), SERVICE_INTERFACE)
+      .unwrap_or_default();
+    let stoppedWith = self.stoppedWith(name, &unit)?;
+
+    Ok(ServiceDetailsModel {
+      fragmentPath: take_property(&mut unit, "FragmentPath").unwrap_or_default(),
+      unitFileState: take_property(&mut unit, "UnitFileState").unwrap_or_default(),
+      unitFilePreset: take_property(&mut unit, "UnitFilePreset").unwrap_or_default(),
+      mainPid: take_property(&mut service, "MainPID").filter(|pid: &u32| *pid != 0),
+      // systemd reports u64::MAX when accounting is disabled for the unit
+      memoryBytes: take_property(&mut service, "MemoryCurrent")
+        .filter(|bytes: &u64| *bytes != u64::MAX),
+      cpuNanoseconds: take_property(&mut service, "CPUUsageNSec")
+        .filter(|ns: &u64| *ns != u64::MAX),
+      startedAt: take_property(&mut unit, "ActiveEnterTimestamp").and_then(format_timestamp),
+      restartCount: take_property(&mut service, "NRestarts").unwrap_or_default(),
+      wants: take_property(&mut unit, "Wants").unwrap_or_default(),
+      requires: take_property(&mut unit, "Requires").unwrap_or_default(),
+      wantedBy: take_property(&mut unit, "WantedBy").unwrap_or_default(),
+      stoppedWith,
+      unit: self.unitModel(&mut unit),
+    })
+  }
+
+  /// Why a failed unit failed. Journal lines are left for the caller to fill in.
+  pub fn getFailedUnit(&self, name: &str) -> Result<FailedUnitModel, AppError> {
+    let path = self.loadUnit(name)?;
+    let mut unit = self.unitProperties(path.as_str(), UNIT_INTERFACE)?;
+    // Every unit type reports its result on its own interface, e.g. ...systemd1.Mount
+    let unitType = name.rsplit('.').next().unwrap_or("service");
+    let mut chars = unitType.chars();
+    let interface = format!(
+      "org.freedesktop.systemd1.{}{}",
+      chars
+        .next()
+        .map(|c| c.to_uppercase().to_string())
+        .unwrap_or_default(),
+      chars.as_str()
+    );
+    let mut typed = self
+      .unitProperties(path.as_str(), &interface)
+      .unwrap_or_default();
+
+    // ExecMainCode is the waitid() code: CLD_EXITED, CLD_KILLED or CLD_DUMPED
+    let code: i32 = take_property(&mut typed, "ExecMainCode").unwrap_or_default();
+    let status: Option<i32> = take_property(&mut typed, "ExecMainStatus");
+    Ok(FailedUnitModel {
+      result: take_property(&mut typed, "Result").unwrap_or_default(),
+      exitStatus: status.filter(|_| code == 1),
+      exitSignal: status.filter(|_| code == 2 || code == 3),
+      failedAt: take_property(&mut unit, "InactiveEnterTimestamp").and_then(format_timestamp),
+      recentLogs: Vec::new(),
+      error: None,
+      unit: self.unitModel(&mut unit),
+    })
+  }
+
+  pub fn getManagerProperties(&self) -> Result<HashMap<String, OwnedValue>, AppError> {
+    self.unitProperties(SYSTEMD_PATH, MANAGER_INTERFACE)
+  }
+
+  /// Properties of every unit the manager currently has loaded.
+  pub fn listUnitProperties(&self) -> Result<Vec<HashMap<String, OwnedValue>>, AppError> {
+    let units: Vec<UnitRecord> = self
+      .manager()?
+      .call("ListUnits", &())
+      .map_err(|e| dbus_error("Failed to list units", e))?;
+
+    units
+      .iter()
+      .map(|(.., path, _, _, _)| self.unitProperties(path.as_str(), UNIT_INTERFACE))
+      .collect()
+  }
+
+  pub fn startUnit(&self, name: &str) -> Result<(), AppError> {
+    self.runUnitJob("StartUnit", name)
+  }
+
+  pub fn stopUnit(&self, name: &str) -> Result<(), AppError> {
+    self.runUnitJob("StopUnit", name)
+  }
+
+  pub fn restartUnit(&self, name: &str) -> Result<(), AppError> {
+    self.runUnitJob("RestartUnit", name)
+  }
+
+  pub fn reloadUnit(&self, name: &str) -> Result<(), AppError> {
+    self.runUnitJob("ReloadUnit", name)
+  }
+
+  pub fn enableUnitFiles(&self, names: &[String]) -> Result<(), AppError> {
+    let _: (bool, Vec<UnitFileChange>) =
+      self.changeUnitFiles("EnableUnitFiles", "enable", names, &(names, false, false))?;
+    Ok(())
+  }
+
+  pub fn disableUnitFiles(&self, names: &[String]) -> Result<(), AppError> {
+    let _: Vec<UnitFileChange> =
+      self.changeUnitFiles("DisableUnitFiles", "disable", names, &(names, false))?;
+    Ok(())
+  }
+
+  pub fn maskUnitFiles(&self, names: &[String]) -> Result<(), AppError> {
+    let _: Vec<UnitFileChange> =
+      self.changeUnitFiles("MaskUnitFiles", "mask", names, &(names, false, false))?;
+    Ok(())
+  }
+
+  pub fn unmaskUnitFiles(&self, names: &[String]) -> Result<(), AppError> {
+    let _: Vec<UnitFileChange> =
+      self.changeUnitFiles("UnmaskUnitFiles", "unmask", names, &(names, false))?;
+    Ok(())
+  }
+
+  pub fn resetFailedUnit(&self, name: &str) -> Result<(), AppError> {
+    let _: Option<()> = self
+      .manager()?
+      .call_with_flags(
+        "ResetFailedUnit",
+        MethodFlags::AllowInteractiveAuth.into(),
+        &(name,),
+      )
+      .map_err(|e| dbus_error(format!("Failed to reset {}", name), e))?;
+    Ok(())
+  }
+
+  fn changeUnitFiles<B, R>(
+    &self,
+    method: &str,
+    verb: &str,
+    names: &[String],
+    body: &B,
+  ) -> Result<R, AppError>
+  where
+    B: serde::Serialize + zbus::zvariant::DynamicType,
+    R: for<'d> zbus::zvariant::DynamicDeserialize<'d>,
+  {
+    let manager = self.manager()?;
+    let changes = manager
+      .call_with_flags(method, MethodFlags::AllowInteractiveAuth.into(), body)
+      .map_err(|e| dbus_error(format!("Failed to {} {}", verb, names.join(", ")), e))?
+      .ok_or_else(|| AppError::internal(format!("systemd returned nothing for {}", method)))?;
+    // Unit file changes only take effect once the manager reloads its configuration
+    let _: Option<()> = manager
+      .call_with_flags("Reload", MethodFlags::AllowInteractiveAuth.into(), &())
+      .map_err(|e| dbus_error("Failed to reload systemd", e))?;
+    Ok(changes)
+  }
+
+  fn runUnitJob(&self, method: &str, name: &str) -> Result<(), AppError> {
+    let manager = self.manager()?;
+    let jobs = manager
+      .receive_signal("JobRemoved")
+      .map_err(|e| dbus_error("Failed to watch systemd jobs", e))?;
+    // Signals are read on a thread so the wait below can time out; the thread ends at
+    // the next job signal once nobody is waiting for it
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+      for message in jobs {
+        if sender.send(message).is_err() {
+          break;
+        }
+      }
+    });
+    // systemd only emits job signals to subscribed clients
+    match manager.call::<_, _, ()>("Subscribe", &()) {
+      Err(zbus::Error::MethodError(name, _, _))
+        if name.as_str() == "org.freedesktop.systemd1.AlreadySubscribed" => {}
+      result => result.map_err(|e| dbus_error("Failed to subscribe to systemd", e))?,
+    }
+
+    let job: OwnedObjectPath = manager
+      .call_with_flags(
+        method,
+        MethodFlags::AllowInteractiveAuth.into(),
+        &(name, "replace"),
+      )
+      .map_err(|e| dbus_error(format!("{} {} failed", method, name), e))?
+      .ok_or_else(|| AppError::internal(format!("systemd returned no job for {}", name)))?;
+
+    let deadline = Instant::now() + self.jobTimeout;
+    loop {
+      let message = match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+      {
+        Ok(message) => message,
+        Err(RecvTimeoutError::Timeout) => {
+          return Err(AppError::new(
+            ErrorCode::Busy,
+            format!(
+              "{} {} did not finish within {} seconds",
+              method,
+              name,
+              self.jobTimeout.as_secs()
+            ),
+          ))
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+          return Err(AppError::internal(format!(
+            "Lost connection to systemd while waiting for {}",
+            name
+          )))
+        }
+      };
+      let Ok((_, path, _, result)) = message
+        .body()
+        .deserialize::<(u32, OwnedObjectPath, String, String)>()
+      else {
+        continue;
+      };
+      if path != job {
+        continue;
+      }
+
+      return match result.as_str() {
+        "done" | "skipped" => Ok(()),
+        "canceled" => Err(AppError::new(
+          ErrorCode::Busy,
+          format!("{} {} was cancelled by another job", method, name),
+        )),
+        _ => Err(AppError::new(
+          ErrorCode::CommandFailed,
+          format!("{} {} finished with result {}", method, name, result),
+        )),
+      };
+    }
+  }
+
+  /// Walks the units that require, are bound to or are part of `name`, since stopping
+  /// `name` propagates a stop job to each of them.
+  fn stoppedWith(
+    &self,
+    name: &str,
+    properties: &HashMap<String, OwnedValue>,
+  ) -> Result<Vec<String>, AppError> {
+    let mut seen: HashSet<String> = HashSet::from([name.to_string()]);
+    let mut queue: VecDeque<String> = reverse_dependencies(properties).into();
+    let mut stopped = Vec::new();
+
+    while let Some(dependent) = queue.pop_front() {
+      if !seen.insert(dependent.clone()) {
+        continue;
+      }
+      let path = self.loadUnit(&dependent)?;
+      let mut properties = self.unitProperties(path.as_str(), UNIT_INTERFACE)?;
+      queue.extend(reverse_dependencies(&properties));
+      let active: String = take_property(&mut properties, "ActiveState").unwrap_or_default();
+      if active != "inactive" && active != "failed" {
+        stopped.push(dependent);
+      }
+    }
+
+    stopped.sort();
+    Ok(stopped)
+  }
+
+  fn unitModel(&self, properties: &mut HashMap<String, OwnedValue>) -> SystemServiceModel {
+    let mut text = |key: &str| take_property::<String>(properties, key).unwrap_or_default();
+    let sub = text("SubState");
+    SystemServiceModel {
+      name: text("Id"),
+      scope: self.scope,
+      description: text("Description"),
+      load: text("LoadState"),
+      active: text("ActiveState"),
+      isRunning: sub == "running",
+      status: sub,
+    }
+  }
+
+  fn loadUnit(&self, name: &str) -> Result<OwnedObjectPath, AppError> {
+    self
+      .manager()?
+      .call("LoadUnit", &(name,))
+      .map_err(|e| dbus_error(format!("Failed to load {}", name), e))
+  }
+
+  fn unitProperties(
+    &self,
+    path: &str,
+    interface: &str,
+  ) -> Result<HashMap<String, OwnedValue>, AppError> {
+    Proxy::new(
+      &self.connection,
+      SYSTEMD_DESTINATION,
+      path,
+      PROPERTIES_INTERFACE,
+    )
+    .and_then(|proxy| proxy.call("GetAll", &(interface,)))
+    .map_err(|e| dbus_error(format!("Failed to read properties of {}", path), e))
+  }
+
+  fn manager(&self) -> Result<Proxy<'static>, AppError> {
+    Proxy::new(
+      &self.connection,
+      SYSTEMD_DESTINATION,
+      SYSTEMD_PATH,
+      MANAGER_INTERFACE,
+    )
+    .map_err(|e| dbus_error("Failed to reach systemd", e))
+  }
+}
+
+pub fn take_property<T: TryFrom<OwnedValue>>(
+  properties: &mut HashMap<String, OwnedValue>,
+  key: &str,
+) -> Option<T> {
+  properties
+    .remove(key)
+    .and_then(|value| T::try_from(value).ok())
+}
+
+/// Converts a CLOCK_MONOTONIC timestamp to microseconds since the epoch.
+fn monotonic_to_realtime(usec: u64) -> u64 {
+  let now = clock_gettime(ClockId::Monotonic);
+  let monotonic_now = now.tv_sec as u64 * 1_000_000 + now.tv_nsec as u64 / 1_000;
+  let realtime_now = Utc::now().timestamp_micros() as u64;
+  (realtime_now + usec).saturating_sub(monotonic_now)
+}
+
+fn reverse_dependencies(properties: &HashMap<String, OwnedValue>) -> Vec<String> {
+  ["RequiredBy", "BoundBy", "ConsistsOf"]
+    .iter()
+    .filter_map(|key| properties.get(*key)?.try_clone().ok())
+    .filter_map(|value| Vec::<String>::try_from(value).ok())
+    .flatten()
+    .collect()
+}
+
+/// Formats a systemd realtime timestamp (microseconds since the epoch, 0 when unset).
+pub fn format_timestamp(usec: u64) -> Option<String> {
+  if usec == 0 {
+    return None;
+  }
+  let micros = i64::try_from(usec).ok()?;
+  Local
+    .timestamp_micros(micros)
+    .single()
+    .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+}
+
+pub fn dbus_error(context: impl fmt::Display, error: zbus::Error) -> AppError {
+  let code = match &error {
+    zbus::Error::MethodError(name, _, _) => match name.as_str() {
+      "org.freedesktop.systemd1.NoSuchUnit"
+      | "org.freedesktop.systemd1.NoSuchUnitFile"
+      | "org.freedesktop.DBus.Error.FileNotFound"
+      | "org.freedesktop.DBus.Error.ServiceUnknown" => ErrorCode::NotFound,
+      "org.freedesktop.DBus.Error.AccessDenied"
+      | "org.freedesktop.DBus.Error.InteractiveAuthorizationRequired" => {
+        ErrorCode::PermissionDenied
+      }
+      "org.freedesktop.PolicyKit1.Error.Cancelled" | "com.tcs.cleanux.Helper.Error.Cancelled" => {
+        ErrorCode::ElevationCancelled
+      }
+      "com.tcs.cleanux.Helper.Error.NotAuthorized" => ErrorCode::PermissionDenied,
+      "org.freedesktop.systemd1.TransactionIsDestructive"
+      | "org.freedesktop.systemd1.TransactionJobsConflicting" => ErrorCode::Busy,
+      "org.freedesktop.systemd1.UnitMasked"
+      | "org.freedesktop.DBus.Error.InvalidArgs"
+      | "com.tcs.cleanux.Helper.Error.InvalidInput" => ErrorCode::InvalidInput,
+      _ => ErrorCode::CommandFailed,
+    },
+    zbus::Error::InputOutput(e) => ErrorCode::fromIoKind(e.kind()),
+    _ => ErrorCode::Internal,
+  };
+  let detail = match &error {
+    zbus::Error::MethodError(_, Some(description), _) => description.clone(),
+    _ => error.to_string(),
+  };
+
+  AppError::new(code, format!("{}: {}", context, detail))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::os::unix::net::UnixStream;
+  use zbus::blocking::connection;
+  use zbus::object_server::SignalEmitter;
+  use zbus::zvariant::ObjectPath;
+
+  const UNIT_PATH: &str = "/org/freedesktop/systemd1/unit/mock";
+  const JOB_PATH: &str = "/org/freedesktop/systemd1/job/1";
+
+  /// Units the mock manager knows: name, active state and sub state.
+  const UNITS: [(&str, &str, &str); 3] = [
+    ("sshd.service", "active", "running"),
+    ("crashed.service", "failed", "failed"),
+    ("cups.service", "inactive", "dead"),
+  ];
+
+  struct MockManager;
+
+  #[zbus::interface(name = "org.freedesktop.systemd1.Manager")]
+  impl MockManager {
+    fn list_units_by_patterns(
+      &self,
+      _states: Vec<String>,
+      _patterns: Vec<String>,
+    ) -> Vec<UnitRecord> {
+      let path = OwnedObjectPath::try_from(UNIT_PATH).unwrap();
+      let none = OwnedObjectPath::try_from("/").unwrap();
+      UNITS
+        .iter()
+        .rev()
+        .map(|(name, active, sub)| {
+          (
+            name.to_string(),
+            format!("Mock {}", name),
+            "loaded".to_string(),
+            active.to_string(),
+            sub.to_string(),
+            String::new(),
+            path.clone(),
+            0,
+            String::new(),
+            none.clone(),
+          )
+        })
+        .collect()
+    }
+
+    fn load_unit(&self, name: String) -> zbus::fdo::Result<OwnedObjectPath> {
+      if name == "missing.service" {
+        return Err(zbus::fdo::Error::FileNotFound(format!(
+          "Unit {} not found",
+          name
+        )));
+      }
+      Ok(OwnedObjectPath::try_from(UNIT_PATH).unwrap())
+    }
+
+    fn subscribe(&self) {}
+
+    /// Finishes the job at once, failing it for `broken.service`.
+    async fn start_unit(
+      &self,
+      name: String,
+      _mode: String,
+      #[zbus(signal_emitter)] emitter: SignalEmitter<'_>,
+    ) -> zbus::fdo::Result<OwnedObjectPath> {
+      let job = ObjectPath::try_from(JOB_PATH).unwrap();
+      let result = if name == "broken.service" {
+        "failed"
+      } else {
+        "done"
+      };
+      Self::job_removed(&emitter, 1, job.clone(), &name, result).await?;
+      Ok(job.into())
+    }
+
+    /// Queues a job that never finishes.
+    fn stop_unit(&self, _name: String, _mode: String) -> OwnedObjectPath {
+      OwnedObjectPath::try_from(JOB_PATH).unwrap()
+    }
+
+    #[zbus(signal)]
+    async fn job_removed(
+      emitter: &SignalEmitter<'_>,
+      id: u32,
+      job: ObjectPath<'_>,
+      unit: &str,
+      result: &str,
+    ) -> zbus::Result<()>;
+  }
+
+  struct MockUnit;
+
+  #[zbus::interface(name = "org.freedesktop.systemd1.Unit")]
+  impl MockUnit {
+    #[zbus(property, name = "Id")]
+    fn id(&self) -> String {
+      "crashed.service".to_string()
+    }
+
+    #[zbus(property, name = "Description")]
+    fn description(&self) -> String {
+      "Mock crashed.service".to_string()
+    }
+
+    #[zbus(property, name = "LoadState")]
+    fn load_state(&self) -> String {
+      "loaded".to_string()
+    }
+
+    #[zbus(property, name = "ActiveState")]
+    fn active_state(&self) -> String {
+      "failed".to_string()
+    }
+
+    #[zbus(property, name = "SubState")]
+    fn sub_state(&self) -> String {
+      "failed".to_string()
+    }
+  }
+
+  struct MockService;
+
+  #[zbus::interface(name = "org.freedesktop.systemd1.Service")]
+  impl MockService {
+    #[zbus(property, name = "Result")]
+    fn result(&self) -> String {
+      "exit-code".to_string()
+    }
+
+    #[zbus(property, name = "ExecMainCode")]
+    fn exec_main_code(&self) -> i32 {
+      1
+    }
+
+    #[zbus(property, name = "ExecMainStatus")]
+    fn exec_main_status(&self) -> i32 {
+      3
+    }
+  }
+
+  /// Connects a `SystemdService` to a mock manager over a private peer-to-peer bus. The
+  /// returned server connection must be kept alive for as long as the service is used.
+  fn mock_systemd() -> (SystemdService, Connection) {
+    let (server, client) = UnixStream::pair().unwrap();
+    let server = thread::spawn(move || {
+      connection::Builder::unix_stream(server)
+        .server(zbus::Guid::generate())
+        .unwrap()
+        .p2p()
+        .serve_at(SYSTEMD_PATH, MockManager)
+        .unwrap()
+        .serve_at(UNIT_PATH, MockUnit)
+        .unwrap()
+        .serve_at(UNIT_PATH, MockService)
+        .unwrap()
+        .build()
+        .unwrap()
+    });
+    let client = connection::Builder::unix_stream(client)
+      .p2p()
+      .build()
+      .unwrap();
+    let server = server.join().unwrap();
+    (SystemdService::new(client, ServiceScope::User), server)
+  }
+
+  #[test]
+  fn lists_services_sorted_by_name() {
+    let (systemd, _server) = mock_systemd();
+    let services = systemd.listServices(&[]).unwrap();
+
+    let names: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["crashed.service", "cups.service", "sshd.service"]);
+    assert!(services[2].isRunning);
+    assert!(!services[1].isRunning);
+    assert_eq!(services[0].active, "failed");
+    assert_eq!(services[0].scope, ServiceScope::User);
+  }
+
+  #[test]
+  fn reads_unit_properties() {
+    let (systemd, _server) = mock_systemd();
+    let unit = systemd.getUnit("crashed.service").unwrap();
+
+    assert_eq!(unit.name, "crashed.service");
+    assert_eq!(unit.description, "Mock crashed.service");
+    assert_eq!(unit.status, "failed");
+  }
+
+  #[test]
+  fn reports_missing_unit_as_not_found() {
+    let (systemd, _server) = mock_systemd();
+    let error = systemd.getUnit("missing.service").err().unwrap();
+
+    assert_eq!(error.code, ErrorCode::NotFound);
+  }
+
+  #[test]
+  fn reads_failure_reason_from_the_typed_interface() {
+    let (systemd, _server) = mock_systemd();
+    let failed = systemd.getFailedUnit("crashed.service").unwrap();
+
+    assert_eq!(failed.result, "exit-code");
+    assert_eq!(failed.exitStatus, Some(3));
+    assert_eq!(failed.exitSignal, None);
+  }
+
+  #[test]
+  fn failed_unit_without_a_type_has_no_reason() {
+    let (systemd, _server) = mock_systemd();
+    let failed = systemd.getFailedUnit("crashed.").unwrap();
+
+    assert_eq!(failed.result, "");
+    assert_eq!(failed.unit.name, "crashed.service");
+  }
+
+  #[test]
+  fn waits_for_the_job_to_finish() {
+    let (systemd, _server) = mock_systemd();
+
+    assert!(systemd.startUnit("sshd.service").is_ok());
+    let error = systemd.startUnit("broken.service").err().unwrap();
+    assert_eq!(error.code, ErrorCode::CommandFailed);
+  }
+
+  #[test]
+  fn gives_up_on_a_job_that_never_finishes() {
+    let (mut systemd, _server) = mock_systemd();
+    systemd.jobTimeout = Duration::from_millis(200);
+
+    let started = Instant::now();
+    let error = systemd.stopUnit("sshd.service").err().unwrap();
+    assert_eq!(error.code, ErrorCode::Busy);
+    assert!(started.elapsed() < Duration::from_secs(5));
+  }
+}